@@ -130,7 +130,7 @@ pub use prebuilt::*;
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Common
 
-const NEXT_MINOR_VERSION: u8 = 3;
+const NEXT_MINOR_VERSION: u8 = 4;
 
 pub fn clear_dir(dir: &Path, watch: &mut StopWatch) {
     if dir.exists() {
@@ -5,6 +5,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::panic::Location;
+
 /// A type that tracks the state of borrows for a [`GdCell`].
 ///
 /// This state upholds these invariants:
@@ -18,6 +20,9 @@
 /// If a catastrophic error occurs, then the state will be poisoned. If the state is poisoned then that's
 /// almost certainly an implementation bug, and should never happen. But in an abundance of caution it is
 /// included to be safe.
+///
+/// In builds with `debug_assertions`, the state additionally records the [`BorrowSite`] of each tracked
+/// borrow, so that errors can report which borrows are in the way.
 #[derive(Debug, Clone, PartialEq)]
 pub struct BorrowState {
     /// The number of `&T` references that are tracked.
@@ -28,6 +33,12 @@ pub struct BorrowState {
     inaccessible_count: usize,
     /// `true` if the borrow state has reached an erroneous or unreliable state.
     poisoned: bool,
+    /// Sites of the tracked borrows, oldest first, each with the ID handed out by [`Self::track_site`].
+    ///
+    /// Stays empty in builds without `debug_assertions`.
+    sites: Vec<(usize, BorrowSite)>,
+    /// The ID for the next site to be tracked.
+    next_site_id: usize,
 }
 
 impl BorrowState {
@@ -38,6 +49,8 @@ impl BorrowState {
             mut_count: 0,
             inaccessible_count: 0,
             poisoned: false,
+            sites: Vec::new(),
+            next_site_id: 0,
         }
     }
 
@@ -70,6 +83,34 @@ impl BorrowState {
         self.poisoned
     }
 
+    /// Returns the sites of the tracked borrows, oldest first.
+    ///
+    /// Always empty in builds without `debug_assertions`.
+    pub fn sites(&self) -> Vec<BorrowSite> {
+        self.sites.iter().map(|(_, site)| *site).collect()
+    }
+
+    /// Records the site of a new borrow, which has been tracked with [`Self::increment_shared`] or
+    /// [`Self::increment_mut`].
+    ///
+    /// Returns an ID to pass to [`Self::untrack_site`] once the borrow ends. The site is only recorded in
+    /// builds with `debug_assertions`.
+    pub(crate) fn track_site(&mut self, site: BorrowSite) -> usize {
+        let id = self.next_site_id;
+        self.next_site_id = self.next_site_id.wrapping_add(1);
+
+        if cfg!(debug_assertions) {
+            self.sites.push((id, site));
+        }
+
+        id
+    }
+
+    /// Removes the site recorded by [`Self::track_site`].
+    pub(crate) fn untrack_site(&mut self, id: usize) {
+        self.sites.retain(|(site_id, _)| *site_id != id);
+    }
+
     /// Set self as having reached an erroneous or unreliable state.
     ///
     /// Always returns [`BorrowStateErr::Poisoned`].
@@ -91,7 +132,7 @@ impl BorrowState {
         self.ensure_not_poisoned()?;
 
         if self.has_accessible() {
-            return Err("cannot borrow while accessible mutable borrow exists".into());
+            return Err(self.blocked());
         }
 
         Ok(())
@@ -100,17 +141,22 @@ impl BorrowState {
     fn ensure_can_mut_ref(&self) -> Result<(), BorrowStateErr> {
         self.ensure_not_poisoned()?;
 
-        if self.has_accessible() {
-            return Err("cannot borrow while accessible mutable borrow exists".into());
-        }
-
-        if self.shared_count != 0 {
-            return Err("cannot borrow mutable while shared borrow exists".into());
+        if self.has_accessible() || self.shared_count != 0 {
+            return Err(self.blocked());
         }
 
         Ok(())
     }
 
+    /// Error describing the current borrows, which prevent a new borrow from being taken.
+    fn blocked(&self) -> BorrowStateErr {
+        BorrowStateErr::Blocked {
+            shared_count: self.shared_count,
+            has_accessible_mut: self.has_accessible(),
+            sites: self.sites(),
+        }
+    }
+
     /// Track a new shared reference.
     ///
     /// Returns the new total number of shared references.
@@ -267,10 +313,48 @@ impl Default for BorrowState {
     }
 }
 
+/// Whether a borrow is shared (`&T`) or mutable (`&mut T`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BorrowKind {
+    Shared,
+    Mut,
+}
+
+/// The place in the code where a borrow was taken.
+///
+/// Only recorded in builds with `debug_assertions`, see [`BorrowState::sites`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BorrowSite {
+    pub kind: BorrowKind,
+    pub location: &'static Location<'static>,
+}
+
+impl std::fmt::Display for BorrowSite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            BorrowKind::Shared => "shared",
+            BorrowKind::Mut => "mutable",
+        };
+
+        write!(f, "{kind} borrow at {}", self.location)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BorrowStateErr {
     Poisoned(String),
     IsPoisoned,
+
+    /// A new borrow was prevented by the borrows that exist at the time.
+    Blocked {
+        /// Number of shared references.
+        shared_count: usize,
+        /// Whether an accessible mutable reference exists.
+        has_accessible_mut: bool,
+        /// Where the existing borrows were taken, oldest first. Empty in builds without `debug_assertions`.
+        sites: Vec<BorrowSite>,
+    },
+
     Custom(String),
 }
 
@@ -279,6 +363,14 @@ impl std::fmt::Display for BorrowStateErr {
         match self {
             BorrowStateErr::Poisoned(err) => write!(f, "the borrow state was poisoned: {err}"),
             BorrowStateErr::IsPoisoned => write!(f, "the borrow state is poisoned"),
+            BorrowStateErr::Blocked {
+                has_accessible_mut: true,
+                ..
+            } => write!(f, "cannot borrow while accessible mutable borrow exists"),
+            BorrowStateErr::Blocked { shared_count, .. } => write!(
+                f,
+                "cannot borrow mutable while shared borrows exist (count: {shared_count})"
+            ),
             BorrowStateErr::Custom(err) => f.write_str(err),
         }
     }
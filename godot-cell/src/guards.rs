@@ -21,6 +21,9 @@ pub struct RefGuard<'a, T> {
     /// The current state of borrows to the borrowed value.
     state: &'a Mutex<CellState<T>>,
 
+    /// ID of the site where this borrow was taken, to untrack it on drop.
+    site_id: usize,
+
     /// A pointer to the borrowed value.
     value: NonNull<T>,
 }
@@ -40,8 +43,16 @@ impl<'a, T> RefGuard<'a, T> {
     ///
     /// These conditions ensure that it is safe to call [`as_ref()`](NonNull::as_ref) on `value` for as long
     /// as the returned guard exists.
-    pub(crate) unsafe fn new(state: &'a Mutex<CellState<T>>, value: NonNull<T>) -> Self {
-        Self { state, value }
+    pub(crate) unsafe fn new(
+        state: &'a Mutex<CellState<T>>,
+        site_id: usize,
+        value: NonNull<T>,
+    ) -> Self {
+        Self {
+            state,
+            site_id,
+            value,
+        }
     }
 }
 
@@ -56,12 +67,9 @@ impl<'a, T> Deref for RefGuard<'a, T> {
 
 impl<'a, T> Drop for RefGuard<'a, T> {
     fn drop(&mut self) {
-        self.state
-            .lock()
-            .unwrap()
-            .borrow_state
-            .decrement_shared()
-            .unwrap();
+        let mut state = self.state.lock().unwrap();
+        state.borrow_state.untrack_site(self.site_id);
+        state.borrow_state.decrement_shared().unwrap();
     }
 }
 
@@ -76,6 +84,7 @@ impl<'a, T> Drop for RefGuard<'a, T> {
 pub struct MutGuard<'a, T> {
     state: &'a Mutex<CellState<T>>,
     count: usize,
+    site_id: usize,
     value: NonNull<T>,
 }
 
@@ -112,11 +121,13 @@ impl<'a, T> MutGuard<'a, T> {
     pub(crate) unsafe fn new(
         state: &'a Mutex<CellState<T>>,
         count: usize,
+        site_id: usize,
         value: NonNull<T>,
     ) -> Self {
         Self {
             state,
             count,
+            site_id,
             value,
         }
     }
@@ -179,12 +190,9 @@ impl<'a, T> DerefMut for MutGuard<'a, T> {
 
 impl<'a, T> Drop for MutGuard<'a, T> {
     fn drop(&mut self) {
-        self.state
-            .lock()
-            .unwrap()
-            .borrow_state
-            .decrement_mut()
-            .unwrap();
+        let mut state = self.state.lock().unwrap();
+        state.borrow_state.untrack_site(self.site_id);
+        state.borrow_state.decrement_mut().unwrap();
     }
 }
 
@@ -29,11 +29,13 @@ mod guards;
 use std::cell::UnsafeCell;
 use std::error::Error;
 use std::marker::PhantomPinned;
+use std::panic::Location;
 use std::pin::Pin;
 use std::ptr::NonNull;
 use std::sync::Mutex;
 
 use borrow_state::BorrowState;
+pub use borrow_state::{BorrowKind, BorrowSite, BorrowStateErr};
 pub use guards::{InaccessibleGuard, MutGuard, RefGuard};
 
 /// A cell which can hand out new `&mut` references to its value even when one already exists. As long as
@@ -68,23 +70,43 @@ impl<T> GdCell<T> {
 
     /// Returns a new shared reference to the contents of the cell.
     ///
-    /// Fails if an accessible mutable reference exists.
-    pub fn borrow(self: Pin<&Self>) -> Result<RefGuard<'_, T>, Box<dyn Error>> {
+    /// Fails if an accessible mutable reference exists. The error then describes the existing borrows.
+    ///
+    /// In builds with `debug_assertions`, the caller's location is recorded until the guard is dropped.
+    #[track_caller]
+    pub fn borrow(self: Pin<&Self>) -> Result<RefGuard<'_, T>, BorrowStateErr> {
         let mut state = self.state.lock().unwrap();
         state.borrow_state.increment_shared()?;
+        let site_id = state.borrow_state.track_site(BorrowSite {
+            kind: BorrowKind::Shared,
+            location: Location::caller(),
+        });
 
         // SAFETY: `increment_shared` succeeded, therefore there cannot currently be any accessible mutable
         // references.
-        unsafe { Ok(RefGuard::new(&self.get_ref().state, state.get_ptr())) }
+        unsafe {
+            Ok(RefGuard::new(
+                &self.get_ref().state,
+                site_id,
+                state.get_ptr(),
+            ))
+        }
     }
 
     /// Returns a new mutable reference to the contents of the cell.
     ///
-    /// Fails if an accessible mutable reference exists, or a shared reference exists.
-    pub fn borrow_mut(self: Pin<&Self>) -> Result<MutGuard<'_, T>, Box<dyn Error>> {
+    /// Fails if an accessible mutable reference exists, or a shared reference exists. The error then describes the existing borrows.
+    ///
+    /// In builds with `debug_assertions`, the caller's location is recorded until the guard is dropped.
+    #[track_caller]
+    pub fn borrow_mut(self: Pin<&Self>) -> Result<MutGuard<'_, T>, BorrowStateErr> {
         let mut state = self.state.lock().unwrap();
         state.borrow_state.increment_mut()?;
         let count = state.borrow_state.mut_count();
+        let site_id = state.borrow_state.track_site(BorrowSite {
+            kind: BorrowKind::Mut,
+            location: Location::caller(),
+        });
         let value = state.get_ptr();
 
         // SAFETY: `increment_mut` succeeded, therefore any existing mutable references are inaccessible.
@@ -98,7 +120,7 @@ impl<T> GdCell<T> {
         // If `make_inaccessible` is called and succeeds, then a mutable reference from this guard is passed
         // in. In which case, we cannot use this guard again until the resulting inaccessible guard is
         // dropped.
-        unsafe { Ok(MutGuard::new(&self.get_ref().state, count, value)) }
+        unsafe { Ok(MutGuard::new(&self.get_ref().state, count, site_id, value)) }
     }
 
     /// Make the current mutable borrow inaccessible, thus freeing the value up to be reborrowed again.
@@ -287,6 +309,72 @@ mod test {
         assert_eq!(*guard3, VAL - 5);
     }
 
+    #[test]
+    fn blocked_error_describes_borrows() {
+        let cell = GdCell::new(0);
+        let cell = cell.as_ref();
+
+        let guard1 = cell.borrow().unwrap();
+        let guard2 = cell.borrow().unwrap();
+        let err = cell.borrow_mut().expect_err("shared borrows exist");
+        assert!(matches!(
+            err,
+            BorrowStateErr::Blocked {
+                shared_count: 2,
+                has_accessible_mut: false,
+                ..
+            }
+        ));
+        drop((guard1, guard2));
+
+        let guard = cell.borrow_mut().unwrap();
+        let err = cell.borrow().expect_err("mutable borrow exists");
+        assert!(matches!(
+            err,
+            BorrowStateErr::Blocked {
+                shared_count: 0,
+                has_accessible_mut: true,
+                ..
+            }
+        ));
+        drop(guard);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn blocked_error_records_sites() {
+        let cell = GdCell::new(0);
+        let cell = cell.as_ref();
+
+        let shared_line = line!() + 1;
+        let guard1 = cell.borrow().unwrap();
+        let guard2 = cell.borrow().unwrap();
+        drop(guard1);
+
+        let Err(BorrowStateErr::Blocked { sites, .. }) = cell.borrow_mut() else {
+            panic!("shared borrow exists");
+        };
+        assert_eq!(sites.len(), 1, "dropped guard is no longer tracked");
+        assert_eq!(sites[0].kind, BorrowKind::Shared);
+        assert_eq!(sites[0].location.file(), file!());
+        assert_eq!(sites[0].location.line(), shared_line + 1);
+        drop(guard2);
+
+        let mut guard = cell.borrow_mut().unwrap();
+        let inaccessible = cell.make_inaccessible(&mut guard).unwrap();
+        let inner_guard = cell.borrow_mut().unwrap();
+
+        let Err(BorrowStateErr::Blocked { sites, .. }) = cell.borrow() else {
+            panic!("mutable borrow exists");
+        };
+        let kinds: Vec<_> = sites.iter().map(|site| site.kind).collect();
+        assert_eq!(kinds, [BorrowKind::Mut, BorrowKind::Mut]);
+
+        drop(inner_guard);
+        drop(inaccessible);
+        drop(guard);
+    }
+
     #[test]
     fn different_inaccessible() {
         const VAL1: i32 = 23456;
@@ -49,6 +49,7 @@ fn to_hardcoded_rust_ident(full_ty: &GodotTy) -> Option<&str> {
         ("bool", None) => "bool",
         ("String", None) => "GString",
         ("Array", None) => "VariantArray",
+        ("Dictionary", None) => "VariantDictionary",
 
         // Types needed for native structures mapping
         ("uint8_t", None) => "u8",
@@ -212,6 +213,19 @@ fn to_rust_type_uncached(full_ty: &GodotTy, ctx: &mut Context) -> RustTy {
                 elem_class: elem_ty.to_string(),
             }
         };
+    } else if let Some(elem_tys) = ty.strip_prefix("typeddictionary::") {
+        // Typed dictionaries with engine classes as keys or values are mapped to untyped ones, like excluded classes in minimal codegen.
+        let (key_ty, value_ty) = elem_tys
+            .split_once(';')
+            .unwrap_or_else(|| panic!("typed dictionary {ty:?} without key/value separator"));
+
+        return if ctx.is_builtin(key_ty) && ctx.is_builtin(value_ty) {
+            let rust_key_ty = to_rust_type(key_ty, None, ctx);
+            let rust_value_ty = to_rust_type(value_ty, None, ctx);
+            RustTy::BuiltinDictionary(quote! { Dictionary<#rust_key_ty, #rust_value_ty> })
+        } else {
+            RustTy::BuiltinIdent(ident("VariantDictionary"))
+        };
     }
 
     // Note: do not check if it's a known engine class, because that will not work in minimal mode (since not all classes are stored)
@@ -8,7 +8,9 @@
 use crate::context::{Context, NotificationEnum};
 use crate::generator::functions_common::{FnCode, FnDefinition, FnDefinitions};
 use crate::generator::method_tables::MethodTableKey;
-use crate::generator::{constants, docs, enums, functions_common, notifications, virtual_traits};
+use crate::generator::{
+    constants, docs, enums, functions_common, notifications, signals, virtual_traits,
+};
 use crate::models::domain::{
     ApiView, Class, ClassLike, ClassMethod, ExtensionApi, FnDirection, FnQualifier, Function,
     ModName, TyName,
@@ -100,6 +102,10 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
     } = make_class_methods(class, &class.methods, ctx);
 
     let enums = enums::make_enums(&class.enums);
+    let signals::SignalCodegen {
+        collection: signal_collection,
+        with_signals_impl,
+    } = signals::make_class_signals(class, view);
     let constants = constants::make_constants(&class.constants);
     let inherits_macro = format_ident!("unsafe_inherits_transitive_{}", class_name.rust_ty);
     let deref_impl = make_deref_impl(class_name, &base_ty);
@@ -111,7 +117,8 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
     // Associated "sidecar" module is made public if there are other symbols related to the class, which are not
     // in top-level godot::engine module (notification enums are not in the sidecar, but in godot::engine::notify).
     // This checks if token streams (i.e. code) is empty.
-    let has_sidecar_module = !enums.is_empty() || !builders.is_empty();
+    let has_sidecar_module =
+        !enums.is_empty() || !builders.is_empty() || !signal_collection.is_empty();
 
     let class_doc = docs::make_class_doc(
         class_name,
@@ -206,6 +213,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
 
             #godot_default_impl
             #deref_impl
+            #with_signals_impl
 
             /// # Safety
             ///
@@ -224,6 +232,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
 
         #builders
         #enums
+        #signal_collection
     };
     // note: TypePtr -> ObjectPtr conversion OK?
 
@@ -37,7 +37,7 @@ pub fn make_class_doc(
 
     let sidecar_line = if has_sidecar_module {
         let module_name = ModName::from_godot(&class_name.godot_ty).rust_mod;
-        format!("* [`{module_name}`][crate::engine::{module_name}]: sidecar module with related enum/flag types\n")
+        format!("* [`{module_name}`][crate::engine::{module_name}]: sidecar module with related enum/flag types and signals\n")
     } else {
         String::new()
     };
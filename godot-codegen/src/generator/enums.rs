@@ -77,6 +77,10 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
                     .ok_or_else(|| crate::builtin::meta::FromGodotError::InvalidEnum.into_error(via))
             }
         }
+
+        impl crate::builtin::meta::ArrayElement for #name {
+            const __FALLIBLE_FROM_VIA: bool = true;
+        }
     }
 }
 
@@ -0,0 +1,178 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Type-safe signal handles for engine classes.
+//
+// Each class declaring signals gets a `SignalsOf{Class}<'c, C>` collection in its sidecar module, with one accessor per signal.
+// Collections of derived classes deref to the collection of their closest base class with signals, so inherited signals are reachable
+// as well. `C` is the class of the object on which signals are accessed (e.g. `Button` for `Gd<Button>`, even for `BaseButton` signals).
+
+use crate::models::domain::{ApiView, Class, ClassLike, ClassSignal, TyName};
+use crate::util;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+pub struct SignalCodegen {
+    /// Collection type and accessors, placed in the sidecar module. Empty if the class declares no signals itself.
+    pub collection: TokenStream,
+
+    /// `impl WithSignals`, placed next to the class. Empty if neither the class nor any base declares signals.
+    pub with_signals_impl: TokenStream,
+}
+
+pub fn make_class_signals(class: &Class, view: &ApiView) -> SignalCodegen {
+    let collection = if class.signals.is_empty() {
+        TokenStream::new()
+    } else {
+        make_signal_collection(class, view)
+    };
+
+    let with_signals_impl = match find_collection_class(class, view) {
+        Some(collection_class) => {
+            let class_name = class.name();
+            let collection_path = make_collection_path(collection_class);
+
+            quote! {
+                impl crate::obj::WithSignals for #class_name {
+                    type SignalCollection<'c> = #collection_path<'c, Self>;
+
+                    fn __signals_from_object(
+                        object: crate::builtin::SignalObject<'_, Self>,
+                    ) -> Self::SignalCollection<'_> {
+                        #collection_path::__new(object)
+                    }
+                }
+            }
+        }
+        None => TokenStream::new(),
+    };
+
+    SignalCodegen {
+        collection,
+        with_signals_impl,
+    }
+}
+
+fn has_own_signals(class: &Class) -> bool {
+    !class.signals.is_empty()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn make_signal_collection(class: &Class, view: &ApiView) -> TokenStream {
+    let class_name = class.name();
+    let collection_name = make_collection_name(class_name);
+    let base_collection_class = find_base(class, view).and_then(|b| find_collection_class(b, view));
+
+    let (field, construct, object_access, deref_impl);
+    if let Some(base_class) = base_collection_class {
+        let base_path = make_collection_path(base_class);
+
+        field = quote! { __base: #base_path<'c, C>, };
+        construct = quote! { Self { __base: #base_path::__new(object) } };
+        object_access = quote! { self.__base.__object() };
+        deref_impl = quote! {
+            impl<'c, C: crate::obj::GodotClass> std::ops::Deref for #collection_name<'c, C> {
+                type Target = #base_path<'c, C>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.__base
+                }
+            }
+
+            impl<'c, C: crate::obj::GodotClass> std::ops::DerefMut for #collection_name<'c, C> {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.__base
+                }
+            }
+        };
+    } else {
+        field = quote! { __object: crate::builtin::SignalObject<'c, C>, };
+        construct = quote! { Self { __object: object } };
+        object_access = quote! { &mut self.__object };
+        deref_impl = TokenStream::new();
+    }
+
+    let accessors = class.signals.iter().map(make_signal_accessor);
+    let collection_doc = format!(
+        "Type-safe signals declared by [`{rust_ty}`][crate::engine::{rust_ty}].\n\n\
+        Obtained via [`Gd::signals()`][crate::obj::Gd::signals]. Signals inherited from base classes are accessible through `Deref`.",
+        rust_ty = class_name.rust_ty,
+    );
+
+    quote! {
+        #[doc = #collection_doc]
+        pub struct #collection_name<'c, C: crate::obj::GodotClass> {
+            #field
+        }
+
+        impl<'c, C: crate::obj::GodotClass> #collection_name<'c, C> {
+            #[doc(hidden)]
+            pub fn __new(object: crate::builtin::SignalObject<'c, C>) -> Self {
+                #construct
+            }
+
+            #[doc(hidden)]
+            pub fn __object(&mut self) -> &mut crate::builtin::SignalObject<'c, C> {
+                #object_access
+            }
+
+            #( #accessors )*
+        }
+
+        #deref_impl
+    }
+}
+
+fn make_signal_accessor(signal: &ClassSignal) -> TokenStream {
+    let signal_name_str = &signal.name;
+    let accessor_name = util::safe_ident(signal_name_str);
+    let param_types = signal.parameters.iter().map(|param| &param.type_);
+
+    let param_list = signal
+        .parameters
+        .iter()
+        .map(|param| param.name.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let doc = format!("Signal `{signal_name_str}({param_list})`.");
+
+    quote! {
+        #[doc = #doc]
+        pub fn #accessor_name(&mut self) -> crate::builtin::TypedSignal<'_, C, ((), #( #param_types, )*)> {
+            crate::builtin::TypedSignal::new(self.__object().reborrow(), #signal_name_str)
+        }
+    }
+}
+
+/// Closest class in the inheritance chain (including `class` itself) that declares signals.
+fn find_collection_class<'a>(class: &'a Class, view: &ApiView<'a>) -> Option<&'a Class> {
+    let mut current = class;
+    while !has_own_signals(current) {
+        current = find_base(current, view)?;
+    }
+
+    Some(current)
+}
+
+fn find_base<'a>(class: &Class, view: &ApiView<'a>) -> Option<&'a Class> {
+    let base = class.inherits.as_ref()?;
+
+    Some(view.get_engine_class(&TyName::from_godot(base)))
+}
+
+fn make_collection_name(class_name: &TyName) -> Ident {
+    format_ident!("SignalsOf{}", class_name.rust_ty)
+}
+
+fn make_collection_path(class: &Class) -> TokenStream {
+    let module = class.mod_name();
+    let collection_name = make_collection_name(class.name());
+
+    quote! { crate::engine::#module::#collection_name }
+}
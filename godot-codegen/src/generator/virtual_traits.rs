@@ -104,6 +104,44 @@ fn special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
             unimplemented!()
         }
 
+        /// Called whenever Godot needs the value that `property` can be reverted to, e.g. for the revert button in the editor inspector.
+        ///
+        /// Should return `Some(value)` to make the property revertible to `value`, or `None` to handle the property normally. By default,
+        /// properties are revertible to the value given in `#[init(default = ...)]`, if any.
+        ///
+        /// This method is named `_property_get_revert` in Godot; it is also used to answer `_property_can_revert`.
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_property_get_revert`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-property-get-revert).
+        fn property_get_revert(&self, property: StringName) -> Option<Variant> {
+            unimplemented!()
+        }
+
+        /// Called whenever Godot retrieves the list of properties, e.g. for the editor inspector or
+        /// [`get_property_list()`](crate::engine::Object::get_property_list).
+        ///
+        /// The returned properties are added to the ones registered via `#[var]` and `#[export]`. Together with
+        /// [`get_property()`](Self::get_property) and [`set_property()`](Self::set_property), this allows properties that
+        /// differ from instance to instance.
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_get_property_list`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-get-property-list).
+        fn get_property_list(&self) -> Vec<crate::builtin::meta::PropertyInfo> {
+            unimplemented!()
+        }
+
+        /// Called for each property before it is shown in the editor inspector or returned from
+        /// [`get_property_list()`](crate::engine::Object::get_property_list).
+        ///
+        /// Modify `property` to adjust how it is presented, e.g. change its `usage` to hide it depending on another property's value.
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_validate_property`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-validate-property).
+        #[cfg(since_api = "4.2")]
+        fn validate_property(&self, property: &mut crate::builtin::meta::PropertyInfo) {
+            unimplemented!()
+        }
+
     }
 }
 
@@ -165,6 +165,7 @@ pub struct Class {
     pub constants: Vec<ClassConstant>,
     pub enums: Vec<Enum>,
     pub methods: Vec<ClassMethod>,
+    pub signals: Vec<ClassSignal>,
 }
 
 impl ClassLike for Class {
@@ -430,6 +431,13 @@ impl fmt::Display for ClassMethod {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+pub struct ClassSignal {
+    pub name: String,
+    pub parameters: Vec<FnParam>,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[derive(Copy, Clone, Debug)]
 pub enum FnDirection {
     /// Godot -> Rust.
@@ -592,6 +600,9 @@ pub enum RustTy {
     /// `Array<i32>`
     BuiltinArray(TokenStream),
 
+    /// `Dictionary<GString, i32>`
+    BuiltinDictionary(TokenStream),
+
     /// C-style raw pointer to a `RustTy`.
     RawPointer { inner: Box<RustTy>, is_const: bool },
 
@@ -642,6 +653,7 @@ impl ToTokens for RustTy {
         match self {
             RustTy::BuiltinIdent(ident) => ident.to_tokens(tokens),
             RustTy::BuiltinArray(path) => path.to_tokens(tokens),
+            RustTy::BuiltinDictionary(path) => path.to_tokens(tokens),
             RustTy::RawPointer {
                 inner,
                 is_const: true,
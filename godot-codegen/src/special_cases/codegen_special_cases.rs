@@ -10,7 +10,7 @@
 // TODO make this file private and only accessed by special_cases.rs.
 
 use crate::context::Context;
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
+use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonSignal, JsonUtilityFunction};
 use crate::special_cases;
 
 pub(crate) fn is_builtin_method_excluded(method: &JsonBuiltinMethod) -> bool {
@@ -38,6 +38,7 @@ fn is_type_excluded(ty: &str, ctx: &mut Context) -> bool {
         match ty {
             RustTy::BuiltinIdent(_) => false,
             RustTy::BuiltinArray(_) => false,
+            RustTy::BuiltinDictionary(_) => false,
             RustTy::RawPointer { inner, .. } => is_rust_type_excluded(inner),
             RustTy::EngineArray { elem_class, .. } => is_class_excluded(elem_class.as_str()),
             RustTy::EngineEnum {
@@ -91,6 +92,16 @@ pub(crate) fn is_class_method_excluded(method: &JsonClassMethod, ctx: &mut Conte
     false
 }
 
+pub(crate) fn is_class_signal_excluded(signal: &JsonSignal, ctx: &mut Context) -> bool {
+    // Exclude if any parameter contains a deleted or excluded type.
+    signal.arguments.as_ref().map_or(false, |args| {
+        args.iter().any(|arg| {
+            special_cases::is_godot_type_deleted(arg.type_.as_str())
+                || is_type_excluded(arg.type_.as_str(), ctx)
+        })
+    })
+}
+
 #[cfg(feature = "codegen-full")]
 pub(crate) fn is_utility_function_excluded(
     _function: &JsonUtilityFunction,
@@ -149,6 +160,8 @@ const SELECTED_CLASSES: &[&str] = &[
     "MainLoop",
     "Marker2D",
     "Mesh",
+    "MultiplayerAPI",
+    "MultiplayerPeer",
     "Node",
     "Node2D",
     "Node3D",
@@ -156,6 +169,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "Object",
     "OS",
     "PackedScene",
+    "PacketPeer",
     "PathFollow2D",
     "PhysicsBody2D",
     "PrimitiveMesh",
@@ -9,6 +9,7 @@
 
 // Naming:
 // * Class methods:             is_class_method_*
+// * Class signals:             is_class_signal_*
 // * Builtin methods:           is_builtin_method_*
 // * Class or builtin methods:  is_method_*
 
@@ -24,7 +25,7 @@
 #![allow(clippy::match_like_matches_macro)] // if there is only one rule
 
 use crate::models::domain::TyName;
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
+use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonSignal, JsonUtilityFunction};
 use crate::special_cases::codegen_special_cases;
 use crate::Context;
 
@@ -71,6 +72,17 @@ pub fn is_class_method_deleted(class_name: &TyName, method: &JsonClassMethod, ct
     }
 }
 
+/// True if a signal is not generated as a type-safe signal handle.
+///
+/// It remains accessible through the untyped `Signal` API.
+pub fn is_class_signal_deleted(
+    _class_name: &TyName,
+    signal: &JsonSignal,
+    ctx: &mut Context,
+) -> bool {
+    codegen_special_cases::is_class_signal_excluded(signal, ctx)
+}
+
 pub fn is_class_deleted(class_name: &TyName) -> bool {
     codegen_special_cases::is_class_excluded(&class_name.godot_ty)
         || is_godot_type_deleted(&class_name.godot_ty)
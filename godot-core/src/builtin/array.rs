@@ -8,7 +8,7 @@
 use godot_ffi as sys;
 
 use crate::builtin::*;
-use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
+use crate::property::{ElementExport, Export, PropertyHintInfo, TypeStringHint, Var};
 use std::fmt;
 use std::marker::PhantomData;
 use sys::{ffi_methods, interface_fn, GodotFfi};
@@ -442,6 +442,24 @@ impl<T: ArrayElement> Array<T> {
         }
     }
 
+    /// Checks that all elements can be converted to `T`, if `T` is an element type whose conversion may fail.
+    ///
+    /// Errors carry the index of the first offending element in their [path](ConvertError::path).
+    fn with_checked_elements(self) -> Result<Self, ConvertError> {
+        if T::__FALLIBLE_FROM_VIA {
+            for index in 0..self.len() {
+                // SAFETY: `index` is in bounds, so `ptr` points to a live variant.
+                let variant = unsafe { Variant::borrow_var_sys(self.ptr(index)) };
+
+                if let Err(err) = T::try_from_variant(variant) {
+                    return Err(err.__in_index(index));
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
     /// Sets the type of the inner array.
     ///
     /// # Safety
@@ -849,13 +867,13 @@ impl<T: ArrayElement> Var for Array<T> {
 
     #[cfg(since_api = "4.2")]
     fn property_hint() -> PropertyHintInfo {
-        if T::Ffi::variant_type() == VariantType::Nil {
+        if <T::Via as GodotType>::Ffi::variant_type() == VariantType::Nil {
             return PropertyHintInfo::with_hint_none("");
         }
 
         PropertyHintInfo {
             hint: crate::engine::global::PropertyHint::ARRAY_TYPE,
-            hint_string: T::godot_type_name().into(),
+            hint_string: T::Via::godot_type_name().into(),
         }
     }
 }
@@ -867,6 +885,14 @@ impl<T: ArrayElement + TypeStringHint> Export for Array<T> {
             hint_string: T::type_string().into(),
         }
     }
+
+    fn element_variant_type() -> Option<VariantType> {
+        Some(<T::Via as GodotType>::Ffi::variant_type())
+    }
+}
+
+impl<T: ArrayElement + TypeStringHint> ElementExport for Array<T> {
+    type Element = T;
 }
 
 impl Export for Array<Variant> {
@@ -948,7 +974,7 @@ impl<T: ArrayElement> GodotFfiVariant for Array<T> {
             })
         };
 
-        array.with_checked_type()
+        array.with_checked_type()?.with_checked_elements()
     }
 }
 
@@ -1156,7 +1182,7 @@ macro_rules! varray {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-/// Represents the type information of a Godot array. See
+/// Represents the type information of a Godot array, or of the keys or values of a Godot dictionary. See
 /// [`set_typed`](https://docs.godotengine.org/en/latest/classes/class_array.html#class-array-method-set-typed).
 ///
 /// We ignore the `script` parameter because it has no impact on typing in Godot.
@@ -1169,7 +1195,15 @@ pub(crate) struct TypeInfo {
 }
 
 impl TypeInfo {
-    fn of<T: GodotType>() -> Self {
+    #[cfg(since_api = "4.4")]
+    pub(crate) fn new(variant_type: VariantType, class_name: StringName) -> Self {
+        Self {
+            variant_type,
+            class_name,
+        }
+    }
+
+    pub(crate) fn of<T: GodotConvert>() -> Self {
         Self {
             variant_type: <T::Via as GodotType>::Ffi::variant_type(),
             class_name: T::Via::class_name().to_string_name(),
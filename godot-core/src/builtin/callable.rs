@@ -7,7 +7,10 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::meta::{impl_godot_as_self, GodotType, ToGodot};
+use crate::builtin::meta::{
+    impl_godot_as_self, CallContext, CallError, FromGodot, GodotType, ParamTuple, ToGodot,
+    VarcallSignatureTuple,
+};
 use crate::builtin::{inner, StringName, Variant, VariantArray};
 use crate::engine::Object;
 use crate::obj::bounds::DynMemory;
@@ -19,12 +22,8 @@ use sys::{ffi_methods, GodotFfi};
 /// A `Callable` represents a function in Godot.
 ///
 /// Usually a callable is a reference to an `Object` and a method name, this is a standard callable. But can
-/// also be a custom callable, which is usually created from `bind`, `unbind`, or a GDScript lambda. See
-/// [`Callable::is_custom`].
-///
-/// Currently it is impossible to use `bind` and `unbind` in GDExtension, see [godot-cpp#802].
-///
-/// [godot-cpp#802]: https://github.com/godotengine/godot-cpp/issues/802
+/// also be a custom callable, which is usually created from [`bind`][Self::bind], [`unbind`][Self::unbind], or a GDScript lambda.
+/// See [`Callable::is_custom`].
 pub struct Callable {
     opaque: sys::types::OpaqueCallable,
 }
@@ -90,6 +89,22 @@ impl Callable {
     /// ```
     #[cfg(since_api = "4.2")]
     pub fn from_fn<F, S>(name: S, rust_function: F) -> Self
+    where
+        F: 'static + Send + Sync + FnMut(&[&Variant]) -> Result<Variant, ()>,
+        S: Into<crate::builtin::GString>,
+    {
+        Self::from_fn_with_owner(name, None, rust_function)
+    }
+
+    /// Like [`from_fn()`][Self::from_fn], but the callable is associated with the object `owner`.
+    ///
+    /// Godot considers such a callable invalid once the owner is freed, and removes its signal connections.
+    #[cfg(since_api = "4.2")]
+    pub(crate) fn from_fn_with_owner<F, S>(
+        name: S,
+        owner: Option<InstanceId>,
+        rust_function: F,
+    ) -> Self
     where
         F: 'static + Send + Sync + FnMut(&[&Variant]) -> Result<Variant, ()>,
         S: Into<crate::builtin::GString>,
@@ -103,6 +118,7 @@ impl Callable {
 
         let info = sys::GDExtensionCallableCustomInfo {
             callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
+            object_id: owner.map_or(0, InstanceId::to_u64),
             call_func: Some(rust_callable_call_fn::<F>),
             free_func: Some(rust_callable_destroy::<FnWrapper<F>>),
             to_string_func: Some(rust_callable_to_string_named::<F>),
@@ -173,6 +189,55 @@ impl Callable {
         self.as_inner().callv(arguments)
     }
 
+    /// Calls the callable with typed arguments, converting the return value to `R`.
+    ///
+    /// Arguments are passed as a tuple, e.g. `callable.call((1, "text"))`; use `()` for no arguments.
+    ///
+    /// Unlike [`callv()`][Self::callv], failures are reported as a [`CallError`] instead of being printed to Godot. This includes:
+    /// - the target object having been freed,
+    /// - the method not existing, or being called with the wrong number of arguments,
+    /// - an argument not being convertible to the corresponding parameter type (the error mentions the parameter index),
+    /// - the return value not being convertible to `R`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn call(callable: Callable) -> Result<(), godot::builtin::meta::CallError> {
+    /// let sum: i64 = callable.call((3, 4))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn call<Ps, R>(&self, args: Ps) -> Result<R, CallError>
+    where
+        Ps: ParamTuple,
+        R: FromGodot,
+    {
+        let call_ctx = CallContext::outbound("Callable", "call");
+
+        if let Some(instance_id) = self.object_id() {
+            if !crate::engine::utilities::is_instance_id_valid(instance_id.to_i64()) {
+                return Err(CallError::failed_dead_object(&call_ctx, instance_id));
+            }
+        }
+
+        let args = Ps::Signature::params_to_variants(args);
+        let result = self
+            .to_variant()
+            .try_call_inner(StringName::from("call"), &args);
+
+        match result {
+            Ok(variant) => R::try_from_variant(&variant)
+                .map_err(|err| CallError::failed_return_conversion::<R>(&call_ctx, err)),
+            Err(err) => {
+                let call_error =
+                    CallError::check_out_varcall(&call_ctx, err, &[] as &[Variant], &args)
+                        .expect_err("variant_call() reported an error");
+
+                Err(call_error)
+            }
+        }
+    }
+
     /// Returns a copy of this Callable with one or more arguments bound, reading them from an array.
     ///
     /// _Godot equivalent: `bindv`_
@@ -180,6 +245,56 @@ impl Callable {
         self.as_inner().bindv(arguments)
     }
 
+    /// Returns a copy of this Callable with the given arguments bound.
+    ///
+    /// Bound arguments are passed after the arguments provided by the caller. Arguments are given as a tuple, e.g.
+    /// `callable.bind((1, "text"))`.
+    ///
+    /// _Godot equivalent: `bind`_
+    pub fn bind<Ps: ParamTuple>(&self, arguments: Ps) -> Self {
+        let arguments = Ps::Signature::params_to_variants(arguments);
+
+        self.bindv(arguments.into_iter().collect())
+    }
+
+    /// Returns a copy of this Callable that ignores the last `arg_count` arguments passed by the caller.
+    ///
+    /// Useful to connect a signal to a method with fewer parameters than the signal provides.
+    ///
+    /// _Godot equivalent: `unbind`_
+    pub fn unbind(&self, arg_count: usize) -> Self {
+        self.as_inner().unbind(arg_count as i64)
+    }
+
+    /// Returns the arguments bound through [`bind()`][Self::bind] or [`bindv()`][Self::bindv].
+    ///
+    /// _Godot equivalent: `get_bound_arguments`_
+    #[doc(alias = "get_bound_arguments")]
+    pub fn bound_args(&self) -> VariantArray {
+        self.as_inner().get_bound_arguments()
+    }
+
+    /// Returns the number of bound arguments, adjusted by [`unbind()`][Self::unbind].
+    ///
+    /// Can be negative if more arguments are unbound than bound.
+    ///
+    /// _Godot equivalent: `get_bound_arguments_count`_
+    #[doc(alias = "get_bound_arguments_count")]
+    pub fn bound_args_count(&self) -> i64 {
+        self.as_inner().get_bound_arguments_count()
+    }
+
+    /// Returns the number of arguments the callable expects, taking bound and unbound arguments into account.
+    ///
+    /// For custom callables without argument count information (e.g. [`from_fn()`][Self::from_fn]), returns 0.
+    ///
+    /// _Godot equivalent: `get_argument_count`_
+    #[doc(alias = "get_argument_count")]
+    #[cfg(since_api = "4.3")]
+    pub fn arg_count(&self) -> usize {
+        self.as_inner().get_argument_count() as usize
+    }
+
     /// Returns the name of the method represented by this callable. If the callable is a lambda function,
     /// returns the function's name.
     ///
@@ -316,10 +431,13 @@ impl fmt::Debug for Callable {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let method = self.method_name();
         let object = self.object();
+        let bound_args = self.bound_args();
 
         f.debug_struct("Callable")
             .field("method", &method)
             .field("object", &object)
+            .field("bound_args", &bound_args)
+            .field("is_custom", &self.is_custom())
             .finish()
     }
 }
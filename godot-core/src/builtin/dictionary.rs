@@ -7,7 +7,10 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::meta::{FromGodot, ToGodot};
+use crate::builtin::meta::{
+    ArrayElement, ConvertError, FromGodot, FromVariantError, GodotConvert, GodotFfiVariant,
+    GodotType, ToGodot,
+};
 use crate::builtin::{inner, Variant, VariantArray};
 use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use std::marker::PhantomData;
@@ -15,23 +18,53 @@ use std::{fmt, ptr};
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
-use super::meta::impl_godot_as_self;
+use super::array_inner::TypeInfo;
 
 /// Godot's `Dictionary` type.
 ///
-/// The keys and values of the dictionary are all `Variant`s, so they can be of different types.
-/// Variants are designed to be generally cheap to clone.
+/// # Typed dictionaries
+///
+/// An untyped dictionary can contain any kind of [`Variant`] as key or value, even different types in the same dictionary.
+/// We represent this in Rust as `VariantDictionary`, which is just a type alias for `Dictionary<Variant, Variant>`.
+///
+/// `Dictionary<K, V>` restricts keys to type `K` and values to type `V`. Both must implement [`ArrayElement`], so typed
+/// collections cannot be nested. Either of them may be `Variant`, to only restrict the other one.
+///
+/// Methods accepting keys or values take any type implementing `ToGodot`, to keep untyped dictionaries convenient.
+/// For typed dictionaries, the argument is checked on insertion and the method panics if it cannot be converted to `K` or `V`.
+/// When a dictionary is converted from Godot (e.g. from a `Variant` or a `#[func]` parameter), the types are checked as well.
+///
+/// Since Godot 4.4, typed dictionaries are also typed on the engine side, so GDScript sees them as `Dictionary[K, V]` and
+/// refuses to insert other types. In earlier versions, typing only exists on the Rust side: conversion from Godot checks each
+/// entry, and entries of the wrong type inserted by other code (e.g. GDScript holding the same dictionary) cause a panic
+/// when read from Rust.
+///
+/// # Reference semantics
+///
+/// Like `Array`, `Dictionary` is a reference type. Cloning it creates a new reference to the same data; use
+/// [`duplicate_shallow()`][Self::duplicate_shallow] or [`duplicate_deep()`][Self::duplicate_deep] to copy the data.
 ///
 /// # Thread safety
 ///
 /// The same principles apply as for [`VariantArray`]. Consult its documentation for details.
-pub struct Dictionary {
+// `repr(transparent)` guarantees that the layout does not depend on `K` and `V`, see `as_untyped()`.
+#[repr(transparent)]
+pub struct Dictionary<K: ArrayElement, V: ArrayElement> {
+    // Safety Invariant: The types of all keys and values in `opaque` match `K` and `V`.
     opaque: OpaqueDictionary,
+    _phantom: PhantomData<(K, V)>,
 }
 
-impl Dictionary {
+/// A Godot `Dictionary` without assigned key and value types.
+pub type VariantDictionary = Dictionary<Variant, Variant>;
+
+impl<K: ArrayElement, V: ArrayElement> Dictionary<K, V> {
     fn from_opaque(opaque: OpaqueDictionary) -> Self {
-        Self { opaque }
+        // Note: type is not yet checked at this point, because dictionary has not yet been initialized!
+        Self {
+            opaque,
+            _phantom: PhantomData,
+        }
     }
 
     /// Constructs an empty `Dictionary`.
@@ -53,7 +86,10 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict.duplicate(true)`_
     pub fn duplicate_deep(&self) -> Self {
-        self.as_inner().duplicate(true)
+        let duplicate = self.as_inner().duplicate(true);
+
+        // SAFETY: The duplicate has the same entries (and since 4.4, the same runtime type) as `self`.
+        unsafe { duplicate.assume_type() }
     }
 
     /// Returns a shallow copy of the dictionary. All dictionary keys and values are copied, but
@@ -65,7 +101,10 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict.duplicate(false)`_
     pub fn duplicate_shallow(&self) -> Self {
-        self.as_inner().duplicate(false)
+        let duplicate = self.as_inner().duplicate(false);
+
+        // SAFETY: The duplicate has the same entries (and since 4.4, the same runtime type) as `self`.
+        unsafe { duplicate.assume_type() }
     }
 
     /// Removes a key from the map, and returns the value associated with
@@ -73,7 +112,7 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `erase`_
     #[doc(alias = "erase")]
-    pub fn remove<K: ToGodot>(&mut self, key: K) -> Option<Variant> {
+    pub fn remove<Q: ToGodot>(&mut self, key: Q) -> Option<V> {
         let key = key.to_variant();
         let old_value = self.get(key.clone());
         self.as_inner().erase(key);
@@ -89,11 +128,11 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `find_key`_
     #[doc(alias = "find_key")]
-    pub fn find_key_by_value<V: ToGodot>(&self, value: V) -> Option<Variant> {
+    pub fn find_key_by_value<W: ToGodot>(&self, value: W) -> Option<K> {
         let key = self.as_inner().find_key(value.to_variant());
 
         if !key.is_nil() || self.contains_key(key.clone()) {
-            Some(key)
+            Some(K::from_variant(&key))
         } else {
             None
         }
@@ -103,13 +142,13 @@ impl Dictionary {
     ///
     /// Note that `NIL` values are returned as `Some(Variant::nil())`, while absent values are returned as `None`.
     /// If you want to treat both as `NIL`, use [`Self::get_or_nil`].
-    pub fn get<K: ToGodot>(&self, key: K) -> Option<Variant> {
+    pub fn get<Q: ToGodot>(&self, key: Q) -> Option<V> {
         let key = key.to_variant();
         if !self.contains_key(key.clone()) {
             return None;
         }
 
-        Some(self.get_or_nil(key))
+        Some(V::from_variant(&self.get_or_nil(key)))
     }
 
     /// Returns the value at the key in the dictionary, or `NIL` otherwise.
@@ -119,7 +158,7 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict.get(key, null)`_
     #[doc(alias = "get")]
-    pub fn get_or_nil<K: ToGodot>(&self, key: K) -> Variant {
+    pub fn get_or_nil<Q: ToGodot>(&self, key: Q) -> Variant {
         self.as_inner().get(key.to_variant(), Variant::nil())
     }
 
@@ -127,7 +166,7 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `has`_
     #[doc(alias = "has")]
-    pub fn contains_key<K: ToGodot>(&self, key: K) -> bool {
+    pub fn contains_key<Q: ToGodot>(&self, key: Q) -> bool {
         let key = key.to_variant();
         self.as_inner().has(key)
     }
@@ -174,6 +213,8 @@ impl Dictionary {
     /// _Godot equivalent: `merge`_
     #[doc(alias = "merge")]
     pub fn extend_dictionary(&mut self, other: Self, overwrite: bool) {
+        // SAFETY: `merge` only reads from `other`, and only writes entries of `other` (which have types `K` and `V`) into `self`.
+        let other = unsafe { other.assume_type::<Variant, Variant>() };
         self.as_inner().merge(other, overwrite)
     }
 
@@ -188,7 +229,10 @@ impl Dictionary {
     /// Insert a value at the given key, returning the previous value for that key (if available).
     ///
     /// If you don't need the previous value, use [`Self::set`] instead.
-    pub fn insert<K: ToGodot, V: ToGodot>(&mut self, key: K, value: V) -> Option<Variant> {
+    ///
+    /// # Panics
+    /// If the dictionary is typed and `key` or `value` cannot be converted to `K` or `V`.
+    pub fn insert<Q: ToGodot, W: ToGodot>(&mut self, key: Q, value: W) -> Option<V> {
         let key = key.to_variant();
         let old_value = self.get(key.clone());
         self.set(key, value);
@@ -200,52 +244,193 @@ impl Dictionary {
     /// If you are interested in the previous value, use [`Self::insert`] instead.
     ///
     /// _Godot equivalent: `dict[key] = value`_
-    pub fn set<K: ToGodot, V: ToGodot>(&mut self, key: K, value: V) {
-        let key = key.to_variant();
+    ///
+    /// # Panics
+    /// If the dictionary is typed and `key` or `value` cannot be converted to `K` or `V`.
+    pub fn set<Q: ToGodot, W: ToGodot>(&mut self, key: Q, value: W) {
+        let key = check_element::<K>(key.to_variant(), "key");
+        let value = check_element::<V>(value.to_variant(), "value");
 
         // SAFETY: `self.get_ptr_mut(key)` always returns a valid pointer to a value in the dictionary; either pre-existing or newly inserted.
         unsafe {
-            value.to_variant().move_into_var_ptr(self.get_ptr_mut(key));
+            value.move_into_var_ptr(self.get_ptr_mut(key));
         }
     }
 
-    /// Returns an iterator over the key-value pairs of the `Dictionary`. The pairs are each of type `(Variant, Variant)`.
+    /// Returns an iterator over the key-value pairs of the `Dictionary`. The pairs are each of type `(K, V)`.
     /// Each pair references the original `Dictionary`, but instead of a `&`-reference to key-value pairs as
     /// you might expect, the iterator returns a (cheap, shallow) copy of each key-value pair.
     ///
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating
     /// over it. This will not result in unsoundness or crashes, but will cause the iterator to
     /// behave in an unspecified way.
-    pub fn iter_shared(&self) -> Iter<'_> {
+    pub fn iter_shared(&self) -> Iter<'_, K, V> {
         Iter::new(self)
     }
 
-    /// Returns an iterator over the keys `Dictionary`. The keys are each of type `Variant`. Each key references
+    /// Returns an iterator over the keys `Dictionary`. The keys are each of type `K`. Each key references
     /// the original `Dictionary`, but instead of a `&`-reference to keys pairs as you might expect, the
     /// iterator returns a (cheap, shallow) copy of each key pair.
     ///
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating
     /// over it. This will not result in unsoundness or crashes, but will cause the iterator to
     /// behave in an unspecified way.
-    pub fn keys_shared(&self) -> Keys<'_> {
+    pub fn keys_shared(&self) -> Keys<'_, K> {
         Keys::new(self)
     }
 
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerDictionary {
-        inner::InnerDictionary::from_outer(self)
+        // The memory layout of `Dictionary<K, V>` does not depend on `K` and `V`.
+        inner::InnerDictionary::from_outer_typed(self)
     }
 
     /// Get the pointer corresponding to the given key in the dictionary.
     ///
     /// If there exists no value at the given key, a `NIL` variant will be inserted for that key.
-    fn get_ptr_mut<K: ToGodot>(&mut self, key: K) -> sys::GDExtensionVariantPtr {
+    fn get_ptr_mut<Q: ToGodot>(&mut self, key: Q) -> sys::GDExtensionVariantPtr {
         let key = key.to_variant();
 
         // Never a null pointer, since entry either existed already or was inserted above.
         // SAFETY: accessing an unknown key _mutably_ creates that entry in the dictionary, with value `NIL`.
         unsafe { interface_fn!(dictionary_operator_index)(self.sys_mut(), key.var_sys()) }
     }
+
+    /// Untyped view on this dictionary, used for read-only access in iterators.
+    fn as_untyped(&self) -> &VariantDictionary {
+        // SAFETY: `Dictionary` is `repr(transparent)` over the opaque type, so the layout does not depend on `K` and `V`.
+        // Any key and value can be read as `Variant`, and the returned shared reference does not allow modification.
+        unsafe { &*(self as *const Self as *const VariantDictionary) }
+    }
+
+    /// Changes the generic types on this dictionary, without changing its contents.
+    ///
+    /// # Safety
+    ///
+    /// - Any keys and values written to the dictionary must match its runtime type.
+    /// - Any keys and values read from the dictionary must be convertible to `L` and `W`.
+    unsafe fn assume_type<L: ArrayElement, W: ArrayElement>(self) -> Dictionary<L, W> {
+        // SAFETY: The memory layout of `Dictionary<K, V>` does not depend on `K` and `V`.
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Checks that the dictionary's entries match the types `K` and `V`.
+    ///
+    /// Since Godot 4.4, this compares the runtime type of the dictionary. Before, each entry is converted.
+    fn with_checked_type(self) -> Result<Self, ConvertError> {
+        #[cfg(since_api = "4.4")]
+        {
+            let (self_key, self_value) = self.type_info();
+
+            let target_key = TypeInfo::of::<K>();
+            if self_key != target_key {
+                return Err(crate::builtin::meta::FromGodotError::BadDictionaryType {
+                    part: "key",
+                    expected: target_key,
+                    actual: self_key,
+                }
+                .into_error(self));
+            }
+
+            let target_value = TypeInfo::of::<V>();
+            if self_value != target_value {
+                return Err(crate::builtin::meta::FromGodotError::BadDictionaryType {
+                    part: "value",
+                    expected: target_value,
+                    actual: self_value,
+                }
+                .into_error(self));
+            }
+        }
+
+        #[cfg(before_api = "4.4")]
+        {
+            let check_keys = TypeInfo::of::<K>().is_typed();
+            let check_values = TypeInfo::of::<V>().is_typed();
+
+            if check_keys || check_values {
+                for (key, value) in self.as_untyped().iter_shared() {
+                    if check_keys {
+                        K::try_from_variant(&key)?;
+                    }
+
+                    if check_values {
+                        V::try_from_variant(&value)
+                            .map_err(|err| err.__in_field(&key.to_string()))?;
+                    }
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the runtime type info of keys and values.
+    #[cfg(since_api = "4.4")]
+    fn type_info(&self) -> (TypeInfo, TypeInfo) {
+        let inner = self.as_inner();
+
+        let key = TypeInfo::new(
+            sys::VariantType::from_sys(inner.get_typed_key_builtin() as sys::GDExtensionVariantType),
+            inner.get_typed_key_class_name(),
+        );
+        let value = TypeInfo::new(
+            sys::VariantType::from_sys(
+                inner.get_typed_value_builtin() as sys::GDExtensionVariantType
+            ),
+            inner.get_typed_value_class_name(),
+        );
+
+        (key, value)
+    }
+
+    /// Sets the key and value types of the inner dictionary.
+    ///
+    /// Before Godot 4.4, dictionaries have no runtime type, and this does nothing.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called once, directly after creation.
+    unsafe fn init_inner_type(&mut self) {
+        debug_assert!(self.is_empty());
+
+        #[cfg(since_api = "4.4")]
+        {
+            let key = TypeInfo::of::<K>();
+            let value = TypeInfo::of::<V>();
+
+            if key.is_typed() || value.is_typed() {
+                let script = Variant::nil();
+
+                // SAFETY: The dictionary is a newly created empty untyped dictionary.
+                unsafe {
+                    interface_fn!(dictionary_set_typed)(
+                        self.sys_mut(),
+                        key.variant_type().sys(),
+                        key.class_name().string_sys(),
+                        script.var_sys(),
+                        value.variant_type().sys(),
+                        value.class_name().string_sys(),
+                        script.var_sys(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks that `variant` can be stored as a key or value of type `T`, panicking otherwise.
+fn check_element<T: ArrayElement>(variant: Variant, what: &str) -> Variant {
+    if TypeInfo::of::<T>().is_typed() {
+        if let Err(err) = T::try_from_variant(&variant) {
+            panic!(
+                "dictionary {what} must be of type {}: {err}",
+                T::Via::godot_type_name()
+            );
+        }
+    }
+
+    variant
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -260,7 +445,7 @@ impl Dictionary {
 //   Dictionaries are properly initialized through a `from_sys` call, but the ref-count should be
 //   incremented as that is the callee's responsibility. Which we do by calling
 //   `std::mem::forget(dictionary.clone())`.
-unsafe impl GodotFfi for Dictionary {
+unsafe impl<K: ArrayElement, V: ArrayElement> GodotFfi for Dictionary<K, V> {
     fn variant_type() -> sys::VariantType {
         sys::VariantType::Dictionary
     }
@@ -268,29 +453,137 @@ unsafe impl GodotFfi for Dictionary {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Opaque; .. }
 }
 
-impl_godot_as_self!(Dictionary);
+// Only implement for untyped dictionaries; typed collections cannot be nested in Godot.
+impl ArrayElement for VariantDictionary {}
+
+impl<K: ArrayElement, V: ArrayElement> GodotConvert for Dictionary<K, V> {
+    type Via = Self;
+}
+
+impl<K: ArrayElement, V: ArrayElement> ToGodot for Dictionary<K, V> {
+    fn to_godot(&self) -> Self::Via {
+        self.clone()
+    }
 
-impl_builtin_traits! {
-    for Dictionary {
-        Default => dictionary_construct_default;
-        Drop => dictionary_destroy;
-        PartialEq => dictionary_operator_equal;
-        // No < operator for dictionaries.
-        // Hash could be added, but without Eq it's not that useful.
+    fn into_godot(self) -> Self::Via {
+        self
+    }
+
+    fn to_variant(&self) -> Variant {
+        self.ffi_to_variant()
     }
 }
 
-impl fmt::Debug for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> FromGodot for Dictionary<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Default for Dictionary<K, V> {
+    #[inline]
+    fn default() -> Self {
+        let mut dictionary = unsafe {
+            Self::new_with_uninit(|self_ptr| {
+                let ctor = sys::builtin_fn!(dictionary_construct_default);
+                ctor(self_ptr, ptr::null_mut())
+            })
+        };
+
+        // SAFETY: We just created this dictionary, and haven't called `init_inner_type` before.
+        unsafe { dictionary.init_inner_type() };
+        dictionary
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Drop for Dictionary<K, V> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            let dictionary_destroy = sys::builtin_fn!(dictionary_destroy);
+            dictionary_destroy(self.sys_mut());
+        }
+    }
+}
+
+// TODO There's a macro for this, but it doesn't support generics yet; add support and use it
+impl<K: ArrayElement, V: ArrayElement> PartialEq for Dictionary<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe {
+            let mut result = false;
+            sys::builtin_call! {
+                dictionary_operator_equal(self.sys(), other.sys(), result.sys_mut())
+            }
+            result
+        }
+    }
+}
+
+// No < operator for dictionaries.
+// Hash could be added, but without Eq it's not that useful.
+
+impl<K: ArrayElement, V: ArrayElement> GodotType for Dictionary<K, V> {
+    type Ffi = Self;
+
+    fn to_ffi(&self) -> Self::Ffi {
+        self.clone()
+    }
+
+    fn into_ffi(self) -> Self::Ffi {
+        self
+    }
+
+    fn try_from_ffi(ffi: Self::Ffi) -> Result<Self, ConvertError> {
+        Ok(ffi)
+    }
+
+    fn godot_type_name() -> String {
+        "Dictionary".into()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> GodotFfiVariant for Dictionary<K, V> {
+    fn ffi_to_variant(&self) -> Variant {
+        unsafe {
+            Variant::new_with_var_uninit(|variant_ptr| {
+                let dictionary_to_variant = sys::builtin_fn!(dictionary_to_variant);
+                dictionary_to_variant(variant_ptr, sys::SysPtr::force_mut(self.sys()));
+            })
+        }
+    }
+
+    fn ffi_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        if variant.get_type() != Self::variant_type() {
+            return Err(FromVariantError::BadType {
+                expected: Self::variant_type(),
+                actual: variant.get_type(),
+            }
+            .into_error(variant.clone()));
+        }
+
+        let dictionary = unsafe {
+            sys::new_with_uninit_or_init::<Self>(|self_ptr| {
+                let dictionary_from_variant = sys::builtin_fn!(dictionary_from_variant);
+                dictionary_from_variant(self_ptr, sys::SysPtr::force_mut(variant.var_sys()));
+            })
+        };
+
+        dictionary.with_checked_type()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> fmt::Debug for Dictionary<K, V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self.to_variant().stringify())
     }
 }
 
-impl fmt::Display for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> fmt::Display for Dictionary<K, V> {
     /// Formats `Dictionary` to match Godot's string representation.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{{ ")?;
-        for (count, (key, value)) in self.iter_shared().enumerate() {
+        for (count, (key, value)) in self.as_untyped().iter_shared().enumerate() {
             if count != 0 {
                 write!(f, ", ")?;
             }
@@ -305,9 +598,10 @@ impl fmt::Display for Dictionary {
 ///
 /// To create a (mostly) independent copy instead, see [`Dictionary::duplicate_shallow()`] and
 /// [`Dictionary::duplicate_deep()`].
-impl Clone for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> Clone for Dictionary<K, V> {
     fn clone(&self) -> Self {
         // SAFETY: `self` is a valid dictionary, since we have a reference that keeps it alive.
+        // The copy refers to the same data, so its entries are of the same types. No need to check them again.
         unsafe {
             Self::new_with_uninit(|self_ptr| {
                 let ctor = sys::builtin_fn!(dictionary_construct_copy);
@@ -318,7 +612,7 @@ impl Clone for Dictionary {
     }
 }
 
-impl Var for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> Var for Dictionary<K, V> {
     fn get_property(&self) -> Self::Via {
         self.to_godot()
     }
@@ -326,31 +620,64 @@ impl Var for Dictionary {
     fn set_property(&mut self, value: Self::Via) {
         *self = FromGodot::from_godot(value)
     }
+
+    #[cfg(since_api = "4.4")]
+    fn property_hint() -> PropertyHintInfo {
+        dictionary_type_hint::<K, V>().unwrap_or_else(|| PropertyHintInfo::with_hint_none(""))
+    }
 }
 
-impl TypeStringHint for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> TypeStringHint for Dictionary<K, V> {
     fn type_string() -> String {
         format!("{}:Dictionary", sys::VariantType::Dictionary as i32)
     }
 }
 
-impl Export for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> Export for Dictionary<K, V> {
     fn default_export_info() -> PropertyHintInfo {
+        #[cfg(since_api = "4.4")]
+        if let Some(hint) = dictionary_type_hint::<K, V>() {
+            return hint;
+        }
+
         PropertyHintInfo::with_hint_none("Dictionary")
     }
 }
 
+/// `DICTIONARY_TYPE` hint for typed dictionaries, or `None` if both key and value are `Variant`.
+#[cfg(since_api = "4.4")]
+fn dictionary_type_hint<K: ArrayElement, V: ArrayElement>() -> Option<PropertyHintInfo> {
+    if !TypeInfo::of::<K>().is_typed() && !TypeInfo::of::<V>().is_typed() {
+        return None;
+    }
+
+    Some(PropertyHintInfo {
+        hint: crate::engine::global::PropertyHint::DICTIONARY_TYPE,
+        hint_string: format!(
+            "{};{}",
+            K::Via::godot_type_name(),
+            V::Via::godot_type_name()
+        )
+        .into(),
+    })
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Conversion traits
 
-/// Creates a dictionary from the given iterator `I` over a `(&K, &V)` key-value pair.
+/// Creates a dictionary from the given iterator `I` over a `(&Q, &W)` key-value pair.
 ///
 /// Each key and value are converted to a `Variant`.
-impl<'a, 'b, K, V, I> From<I> for Dictionary
+///
+/// # Panics
+/// If the dictionary is typed and a key or value cannot be converted to `K` or `V`.
+impl<'a, 'b, K, V, Q, W, I> From<I> for Dictionary<K, V>
 where
-    I: IntoIterator<Item = (&'a K, &'b V)>,
-    K: ToGodot + 'a,
-    V: ToGodot + 'b,
+    K: ArrayElement,
+    V: ArrayElement,
+    I: IntoIterator<Item = (&'a Q, &'b W)>,
+    Q: ToGodot + 'a,
+    W: ToGodot + 'b,
 {
     fn from(iterable: I) -> Self {
         iterable
@@ -364,16 +691,21 @@ where
 ///
 /// Inserts all key-value pairs from the iterator into the dictionary. Previous values for keys appearing
 /// in `iter` will be overwritten.
-impl<K: ToGodot, V: ToGodot> Extend<(K, V)> for Dictionary {
-    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+///
+/// # Panics
+/// If the dictionary is typed and a key or value cannot be converted to `K` or `V`.
+impl<K: ArrayElement, V: ArrayElement, Q: ToGodot, W: ToGodot> Extend<(Q, W)> for Dictionary<K, V> {
+    fn extend<I: IntoIterator<Item = (Q, W)>>(&mut self, iter: I) {
         for (k, v) in iter.into_iter() {
             self.set(k.to_variant(), v.to_variant())
         }
     }
 }
 
-impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for Dictionary {
-    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+impl<K: ArrayElement, V: ArrayElement, Q: ToGodot, W: ToGodot> FromIterator<(Q, W)>
+    for Dictionary<K, V>
+{
+    fn from_iter<I: IntoIterator<Item = (Q, W)>>(iter: I) -> Self {
         let mut dict = Dictionary::new();
         dict.extend(iter);
         dict
@@ -385,16 +717,16 @@ impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for Dictionary {
 /// Internal helper for different iterator impls -- not an iterator itself
 struct DictionaryIter<'a> {
     last_key: Option<Variant>,
-    dictionary: &'a Dictionary,
+    dictionary: &'a VariantDictionary,
     is_first: bool,
     next_idx: usize,
 }
 
 impl<'a> DictionaryIter<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+    fn new<K: ArrayElement, V: ArrayElement>(dictionary: &'a Dictionary<K, V>) -> Self {
         Self {
             last_key: None,
-            dictionary,
+            dictionary: dictionary.as_untyped(),
             is_first: true,
             next_idx: 0,
         }
@@ -434,7 +766,7 @@ impl<'a> DictionaryIter<'a> {
         (remaining, Some(remaining))
     }
 
-    fn call_init(dictionary: &Dictionary) -> Option<Variant> {
+    fn call_init(dictionary: &VariantDictionary) -> Option<Variant> {
         let variant: Variant = Variant::nil();
         let iter_fn = |dictionary, next_value: sys::GDExtensionVariantPtr, valid| unsafe {
             interface_fn!(variant_iter_init)(dictionary, sys::SysPtr::as_uninit(next_value), valid)
@@ -443,7 +775,7 @@ impl<'a> DictionaryIter<'a> {
         Self::ffi_iterate(iter_fn, dictionary, variant)
     }
 
-    fn call_next(dictionary: &Dictionary, last_key: Variant) -> Option<Variant> {
+    fn call_next(dictionary: &VariantDictionary, last_key: Variant) -> Option<Variant> {
         let iter_fn = |dictionary, next_value, valid| unsafe {
             interface_fn!(variant_iter_next)(dictionary, next_value, valid)
         };
@@ -461,7 +793,7 @@ impl<'a> DictionaryIter<'a> {
             sys::GDExtensionVariantPtr,
             *mut sys::GDExtensionBool,
         ) -> sys::GDExtensionBool,
-        dictionary: &Dictionary,
+        dictionary: &VariantDictionary,
         mut next_value: Variant,
     ) -> Option<Variant> {
         let dictionary = dictionary.to_variant();
@@ -495,29 +827,33 @@ impl<'a> DictionaryIter<'a> {
 /// An iterator over key-value pairs from a `Dictionary`.
 ///
 /// See [Dictionary::iter_shared()] for more information about iteration over dictionaries.
-pub struct Iter<'a> {
+pub struct Iter<'a, K, V> {
     iter: DictionaryIter<'a>,
+    _phantom: PhantomData<(K, V)>,
 }
 
-impl<'a> Iter<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+impl<'a, K: ArrayElement, V: ArrayElement> Iter<'a, K, V> {
+    fn new(dictionary: &'a Dictionary<K, V>) -> Self {
         Self {
             iter: DictionaryIter::new(dictionary),
+            _phantom: PhantomData,
         }
     }
 
     /// Creates an iterator that converts each `(Variant, Variant)` key-value pair into a `(K, V)` key-value
     /// pair, panicking upon conversion failure.
-    pub fn typed<K: FromGodot, V: FromGodot>(self) -> TypedIter<'a, K, V> {
-        TypedIter::from_untyped(self)
+    pub fn typed<L: FromGodot, W: FromGodot>(self) -> TypedIter<'a, L, W> {
+        TypedIter::from_untyped(self.iter)
     }
 }
 
-impl<'a> Iterator for Iter<'a> {
-    type Item = (Variant, Variant);
+impl<'a, K: ArrayElement, V: ArrayElement> Iterator for Iter<'a, K, V> {
+    type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next_key_value()
+        self.iter
+            .next_key_value()
+            .map(|(key, value)| (K::from_variant(&key), V::from_variant(&value)))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -530,21 +866,23 @@ impl<'a> Iterator for Iter<'a> {
 /// An iterator over keys from a `Dictionary`.
 ///
 /// See [Dictionary::keys_shared()] for more information about iteration over dictionaries.
-pub struct Keys<'a> {
+pub struct Keys<'a, K> {
     iter: DictionaryIter<'a>,
+    _phantom: PhantomData<K>,
 }
 
-impl<'a> Keys<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+impl<'a, K: ArrayElement> Keys<'a, K> {
+    fn new<V: ArrayElement>(dictionary: &'a Dictionary<K, V>) -> Self {
         Self {
             iter: DictionaryIter::new(dictionary),
+            _phantom: PhantomData,
         }
     }
 
     /// Creates an iterator that will convert each `Variant` key into a key of type `K`,
     /// panicking upon failure to convert.
-    pub fn typed<K: FromGodot>(self) -> TypedKeys<'a, K> {
-        TypedKeys::from_untyped(self)
+    pub fn typed<L: FromGodot>(self) -> TypedKeys<'a, L> {
+        TypedKeys::from_untyped(self.iter)
     }
 
     /// Returns an array of the keys
@@ -555,11 +893,11 @@ impl<'a> Keys<'a> {
     }
 }
 
-impl<'a> Iterator for Keys<'a> {
-    type Item = Variant;
+impl<'a, K: ArrayElement> Iterator for Keys<'a, K> {
+    type Item = K;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next_key()
+        self.iter.next_key().map(|key| K::from_variant(&key))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -580,9 +918,9 @@ pub struct TypedIter<'a, K, V> {
 }
 
 impl<'a, K, V> TypedIter<'a, K, V> {
-    fn from_untyped(value: Iter<'a>) -> Self {
+    fn from_untyped(iter: DictionaryIter<'a>) -> Self {
         Self {
-            iter: value.iter,
+            iter,
             _k: PhantomData,
             _v: PhantomData,
         }
@@ -614,9 +952,9 @@ pub struct TypedKeys<'a, K> {
 }
 
 impl<'a, K> TypedKeys<'a, K> {
-    fn from_untyped(value: Keys<'a>) -> Self {
+    fn from_untyped(iter: DictionaryIter<'a>) -> Self {
         Self {
-            iter: value.iter,
+            iter,
             _k: PhantomData,
         }
     }
@@ -661,7 +999,7 @@ impl<'a, K: FromGodot> Iterator for TypedKeys<'a, K> {
 macro_rules! dict {
     ($($key:tt: $value:expr),* $(,)?) => {
         {
-            let mut d = $crate::builtin::Dictionary::new();
+            let mut d = $crate::builtin::VariantDictionary::new();
             $(
                 // `cargo check` complains that `(1 + 2): true` has unused parens, even though it's not
                 // possible to omit the parens.
@@ -7,6 +7,7 @@
 
 use crate::builtin::meta::{CallContext, ConvertError, ErasedConvertError, ToGodot};
 use crate::builtin::Variant;
+use crate::obj::InstanceId;
 use crate::sys;
 use godot_ffi::{join_debug, VariantType};
 use std::error::Error;
@@ -105,22 +106,45 @@ impl CallError {
     // ------------------------------------------------------------------------------------------------------------------------------------------
     // Constructors returning Result<(), Self>; possible failure
 
-    /// Checks whether number of arguments matches the number of parameters.
+    /// Checks that `arg_count` is valid for a function with `param_count` parameters, the last `default_count` of which are optional.
     pub(crate) fn check_arg_count(
         call_ctx: &CallContext,
         arg_count: usize,
+        default_count: usize,
         param_count: usize,
     ) -> Result<(), Self> {
-        // This will need to be adjusted once optional parameters are supported in #[func].
-        if arg_count == param_count {
+        let required_count = param_count.saturating_sub(default_count);
+        if (required_count..=param_count).contains(&arg_count) {
             return Ok(());
         }
 
-        let call_error = Self::failed_param_count(call_ctx, arg_count, param_count);
+        let call_error = Self::failed_param_count(call_ctx, arg_count, default_count, param_count);
 
         Err(call_error)
     }
 
+    /// Checks that `arg_count` is valid for a variadic function with `param_count` fixed parameters.
+    pub(crate) fn check_variadic_arg_count(
+        call_ctx: &CallContext,
+        arg_count: usize,
+        param_count: usize,
+    ) -> Result<(), Self> {
+        if arg_count >= param_count {
+            return Ok(());
+        }
+
+        let param_plural = plural(param_count);
+        let arg_plural = plural(arg_count);
+
+        Err(Self::new(
+            call_ctx,
+            format!(
+                "variadic function has {param_count} fixed parameter{param_plural}, but received {arg_count} argument{arg_plural}"
+            ),
+            None,
+        ))
+    }
+
     /// Checks the Godot side of a varcall (low-level `sys::GDExtensionCallError`).
     pub(crate) fn check_out_varcall<T: ToGodot>(
         call_ctx: &CallContext,
@@ -196,12 +220,11 @@ impl CallError {
 
     /// Returns an error for a failed return type conversion.
     ///
-    /// **Note:** There are probably no practical scenarios where this occurs. Different calls:
+    /// Different calls:
     /// - outbound engine API: return values are statically typed (correct by binding) or Variant (infallible)
     /// - #[func] methods: dynamic calls return Variant
     /// - GDScript -> Rust calls: value is checked on GDScript side (at parse or runtime), not involving this.
-    ///
-    /// It might only occur if there are mistakes in the binding, or if we at some point add typed dynamic calls, à la `call<R>((1, "str"))`.
+    /// - typed dynamic calls, à la `Callable::call::<_, R>((1, "str"))`: the returned `Variant` may not be convertible to `R`.
     pub(crate) fn failed_return_conversion<R>(
         call_ctx: &CallContext,
         convert_error: ConvertError,
@@ -215,18 +238,34 @@ impl CallError {
         )
     }
 
+    /// Returns an error for a call whose target object has already been freed.
+    pub(crate) fn failed_dead_object(call_ctx: &CallContext, instance_id: InstanceId) -> Self {
+        Self::new(
+            call_ctx,
+            format!("target object {instance_id} has been freed"),
+            None,
+        )
+    }
+
     fn failed_param_count(
         call_ctx: &CallContext,
         arg_count: usize,
+        default_count: usize,
         param_count: usize,
     ) -> CallError {
         let param_plural = plural(param_count);
         let arg_plural = plural(arg_count);
 
+        let optional = if default_count > 0 {
+            format!(" ({default_count} optional)")
+        } else {
+            String::new()
+        };
+
         Self::new(
             call_ctx,
             format!(
-                "function has {param_count} parameter{param_plural}, but received {arg_count} argument{arg_plural}"
+                "function has {param_count} parameter{param_plural}{optional}, but received {arg_count} argument{arg_plural}"
             ),
             None,
         )
@@ -267,7 +306,9 @@ impl CallError {
             | sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS => {
                 let arg_count = arg_types.len() - vararg_offset;
                 let param_count = expected as usize;
-                Self::failed_param_count(call_ctx, arg_count, param_count)
+
+                // Engine methods have no Rust-side default parameters.
+                Self::failed_param_count(call_ctx, arg_count, 0, param_count)
             }
             sys::GDEXTENSION_CALL_ERROR_INSTANCE_IS_NULL => {
                 Self::new(call_ctx, "instance is null", None)
@@ -7,7 +7,7 @@
 
 use godot_ffi as sys;
 use std::collections::HashMap;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
@@ -18,6 +18,9 @@ use sys::Global;
 // but we don't know how many classes).
 static CACHED_STRING_NAMES: Global<HashMap<ClassName, Box<StringName>>> = Global::default();
 
+// Class names that are only known at runtime. They are leaked once, which is bounded by the number of distinct class names.
+static DYNAMIC_CLASS_NAMES: Global<HashMap<String, &'static CStr>> = Global::default();
+
 /// Name of a class registered with Godot.
 ///
 /// Holds the Godot name, not the Rust name (they sometimes differ, e.g. Godot `CSGMesh3D` vs Rust `CsgMesh3D`).
@@ -49,6 +52,21 @@ impl ClassName {
         Self { c_str }
     }
 
+    /// Construct from a class name only known at runtime, e.g. one received from Godot.
+    ///
+    /// # Panics
+    /// If the string contains internal null bytes.
+    pub(crate) fn from_dynamic(name: &str) -> Self {
+        let mut map = DYNAMIC_CLASS_NAMES.lock();
+
+        let c_str = *map.entry(name.to_string()).or_insert_with(|| {
+            let c_string = CString::new(name).expect("class name must not contain null bytes");
+            Box::leak(c_string.into_boxed_c_str())
+        });
+
+        Self { c_str }
+    }
+
     #[doc(hidden)]
     pub fn none() -> Self {
         // In Godot, an empty class name means "no class".
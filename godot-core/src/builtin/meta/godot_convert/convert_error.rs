@@ -25,6 +25,8 @@ type Cause = Box<dyn Error + Send + Sync>;
 pub struct ConvertError {
     kind: ErrorKind,
     value: Option<Variant>,
+    /// Outermost segment first.
+    path: Vec<PathSegment>,
 }
 
 impl ConvertError {
@@ -46,6 +48,7 @@ impl ConvertError {
         Self {
             kind,
             value: Some(value.to_variant()),
+            path: Vec::new(),
         }
     }
 
@@ -69,6 +72,7 @@ impl ConvertError {
         Self {
             kind: ErrorKind::Custom(Some(error.into())),
             value: Some(value.to_variant()),
+            path: Vec::new(),
         }
     }
 
@@ -85,6 +89,40 @@ impl ConvertError {
         self.value.as_ref()
     }
 
+    /// Returns the location of the value that failed to convert, inside a compound value such as a `#[godot(dictionary)]` struct.
+    ///
+    /// For example, `stats.hp` or `items[2]`. Empty if the error did not occur inside a compound value.
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+
+        for segment in &self.path {
+            match segment {
+                PathSegment::Field(name) if path.is_empty() => path.push_str(name),
+                PathSegment::Field(name) => {
+                    path.push('.');
+                    path.push_str(name);
+                }
+                PathSegment::Index(index) => path.push_str(&format!("[{index}]")),
+            }
+        }
+
+        path
+    }
+
+    /// Marks this error as occurring in the field `name` of the value being converted.
+    #[doc(hidden)]
+    pub fn __in_field(mut self, name: &str) -> Self {
+        self.path.insert(0, PathSegment::Field(name.to_string()));
+        self
+    }
+
+    /// Marks this error as occurring in the element at `index` of the value being converted.
+    #[doc(hidden)]
+    pub fn __in_index(mut self, index: usize) -> Self {
+        self.path.insert(0, PathSegment::Index(index));
+        self
+    }
+
     /// Converts error into generic error type. It is useful to send error across thread.
     /// Do note that some data might get lost during conversion.
     pub fn into_erased(self) -> impl Error + Send + Sync {
@@ -94,6 +132,10 @@ impl ConvertError {
 
 impl fmt::Display for ConvertError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "at `{}`: ", self.path())?;
+        }
+
         write!(f, "{}", self.kind)?;
 
         if let Some(value) = &self.value {
@@ -118,10 +160,17 @@ impl Default for ConvertError {
         Self {
             kind: ErrorKind::Custom(None),
             value: None,
+            path: Vec::new(),
         }
     }
 }
 
+#[derive(Debug)]
+enum PathSegment {
+    Field(String),
+    Index(usize),
+}
+
 /// Erased type of [`ConvertError`].
 #[derive(Debug)]
 pub(crate) struct ErasedConvertError {
@@ -176,6 +225,13 @@ pub(crate) enum FromGodotError {
         expected: array_inner::TypeInfo,
         actual: array_inner::TypeInfo,
     },
+    /// `part` is either `"key"` or `"value"`.
+    #[cfg(since_api = "4.4")]
+    BadDictionaryType {
+        part: &'static str,
+        expected: array_inner::TypeInfo,
+        actual: array_inner::TypeInfo,
+    },
     /// InvalidEnum is also used by bitfields.
     InvalidEnum,
     ZeroInstanceId,
@@ -224,6 +280,36 @@ impl fmt::Display for FromGodotError {
                     actual.class_name()
                 )
             }
+            #[cfg(since_api = "4.4")]
+            Self::BadDictionaryType {
+                part,
+                expected,
+                actual,
+            } => {
+                if expected.variant_type() != actual.variant_type() {
+                    return if expected.is_typed() {
+                        write!(
+                            f,
+                            "expected dictionary with {part}s of type {:?}, got {part}s of type {:?}",
+                            expected.variant_type(),
+                            actual.variant_type()
+                        )
+                    } else {
+                        write!(
+                            f,
+                            "expected dictionary with untyped {part}s, got {part}s of type {:?}",
+                            actual.variant_type()
+                        )
+                    };
+                }
+
+                write!(
+                    f,
+                    "expected dictionary with {part}s of class {}, got {part}s of class {}",
+                    expected.class_name(),
+                    actual.class_name()
+                )
+            }
             Self::InvalidEnum => write!(f, "invalid engine enum value"),
             Self::ZeroInstanceId => write!(f, "`InstanceId` cannot be 0"),
         }
@@ -80,7 +80,7 @@ mod sealed {
     impl Sealed for Signal {}
     impl Sealed for Transform2D {}
     impl Sealed for Transform3D {}
-    impl Sealed for Dictionary {}
+    impl<K: ArrayElement, V: ArrayElement> Sealed for Dictionary<K, V> {}
     impl Sealed for bool {}
     impl Sealed for i64 {}
     impl Sealed for i32 {}
@@ -245,7 +245,8 @@ where
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-/// Marker trait to identify types that can be stored in [`Array<T>`][crate::builtin::Array].
+/// Marker trait to identify types that can be stored in [`Array<T>`][crate::builtin::Array], and as keys or values of
+/// [`Dictionary<K, V>`][crate::builtin::Dictionary].
 ///
 /// The types for which this trait is implemented, overlap mostly with [`GodotType`].
 /// This is done consistently what GDScript allows inside `Array[T]`.
@@ -253,12 +254,23 @@ where
 /// Notable differences are:
 /// - Only `VariantArray`, not `Array<T>` is allowed (typed arrays cannot be nested).
 /// - `Option` is only supported for `Option<Gd<T>>`, but not e.g. `Option<i32>`.
+///
+/// Elements are stored as their [`Via`][GodotConvert::Via] type, so the array's runtime type is that of `Via`. This trait is also
+/// implemented for engine enums and types deriving [`GodotConvert`][crate::register::GodotConvert]; for example, an
+/// `Array<MyEnum>` with `#[godot(via = i64)]` is an `Array[int]` in Godot.
 #[diagnostic::on_unimplemented(
     message = "`Array<T>` can only store element types supported in Godot arrays (no nesting).",
     label = "does not implement `Var`",
     note = "see also: https://godot-rust.github.io/docs/gdext/master/godot/builtin/meta/trait.ArrayElement.html"
 )]
-pub trait ArrayElement: GodotType {}
+pub trait ArrayElement: ToGodot + FromGodot + 'static {
+    /// Whether converting from the `Via` type can fail for values of the correct runtime type, e.g. invalid enum values.
+    ///
+    /// If `true`, every element is converted when an `Array<Self>` is obtained from a `Variant`, so that errors are reported
+    /// for the offending index instead of panicking on later access.
+    #[doc(hidden)]
+    const __FALLIBLE_FROM_VIA: bool = false;
+}
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
@@ -292,6 +304,78 @@ impl PropertyInfo {
         }
     }
 
+    /// Converts to the FFI type, transferring ownership of the strings to the returned value.
+    ///
+    /// The result must be released with [`free_owned_property_sys()`][Self::free_owned_property_sys].
+    pub(crate) fn into_owned_property_sys(self) -> sys::GDExtensionPropertyInfo {
+        use crate::obj::EngineBitfield as _;
+        use crate::obj::EngineEnum as _;
+
+        // StringName and GString are #[repr(transparent)], so pointers to the boxes are valid sys pointers.
+        let name = Box::into_raw(Box::new(self.property_name));
+        let hint_string = Box::into_raw(Box::new(self.hint_string));
+
+        sys::GDExtensionPropertyInfo {
+            type_: self.variant_type.sys(),
+            name: name.cast(),
+            // Class names are cached indefinitely, see ClassName::string_sys().
+            class_name: sys::SysPtr::force_mut(self.class_name.string_sys()),
+            hint: u32::try_from(self.hint.ord()).expect("hint.ord()"),
+            hint_string: hint_string.cast(),
+            usage: u32::try_from(self.usage.ord()).expect("usage.ord()"),
+        }
+    }
+
+    /// Releases the strings of a value returned by [`into_owned_property_sys()`][Self::into_owned_property_sys].
+    ///
+    /// # Safety
+    /// `info` must have been created by `into_owned_property_sys()` and not been freed before.
+    pub(crate) unsafe fn free_owned_property_sys(info: sys::GDExtensionPropertyInfo) {
+        drop(Box::from_raw(info.name.cast::<StringName>()));
+        drop(Box::from_raw(info.hint_string.cast::<GString>()));
+    }
+
+    /// Creates a `PropertyInfo` from a property info owned by Godot, copying its strings.
+    ///
+    /// # Safety
+    /// The string pointers in `info` must point to live `StringName`/`GString` values.
+    pub(crate) unsafe fn new_from_sys(info: &sys::GDExtensionPropertyInfo) -> Self {
+        use crate::obj::EngineBitfield as _;
+        use crate::obj::EngineEnum as _;
+
+        let class_name = &*info.class_name.cast::<StringName>();
+        let class_name = if class_name.is_empty() {
+            ClassName::none()
+        } else {
+            ClassName::from_dynamic(&class_name.to_string())
+        };
+
+        Self {
+            variant_type: VariantType::from_sys(info.type_),
+            class_name,
+            property_name: (*info.name.cast::<StringName>()).clone(),
+            hint: global::PropertyHint::from_ord(info.hint as i32),
+            hint_string: (*info.hint_string.cast::<GString>()).clone(),
+            usage: global::PropertyUsageFlags::from_ord(info.usage as u64),
+        }
+    }
+
+    /// Writes this property back into a property info owned by Godot, assigning to the strings it points to.
+    ///
+    /// # Safety
+    /// The string pointers in `info` must point to live `StringName`/`GString` values, which may be overwritten.
+    pub(crate) unsafe fn write_into_sys(self, info: &mut sys::GDExtensionPropertyInfo) {
+        use crate::obj::EngineBitfield as _;
+        use crate::obj::EngineEnum as _;
+
+        info.type_ = self.variant_type.sys();
+        *info.name.cast::<StringName>() = self.property_name;
+        *info.class_name.cast::<StringName>() = self.class_name.to_string_name();
+        info.hint = u32::try_from(self.hint.ord()).expect("hint.ord()");
+        *info.hint_string.cast::<GString>() = self.hint_string;
+        info.usage = u32::try_from(self.usage.ord()).expect("usage.ord()");
+    }
+
     pub fn empty_sys() -> sys::GDExtensionPropertyInfo {
         use crate::obj::EngineBitfield as _;
         use crate::obj::EngineEnum as _;
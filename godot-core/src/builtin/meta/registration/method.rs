@@ -46,9 +46,12 @@ impl ClassMethodInfo {
     ///
     /// `call_func`, if provided, must:
     ///
-    /// - Interpret its parameters as a list of `S::PARAM_COUNT` `Variant`s.
+    /// - Interpret its parameters as a list of `S::PARAM_COUNT` `Variant`s. If `method_flags` contains `VARARG`, accept any number of
+    ///   additional `Variant`s.
     /// - Return a `Variant`.
     ///
+    /// `ptrcall_func` must be `None` if `method_flags` contains `VARARG`, since Godot only uses varcalls for variadic methods.
+    ///
     /// `call_func` and `ptrcall_func`, if provided, must:
     ///
     /// - Follow the behavior expected from the `method_flags`.
@@ -84,6 +87,13 @@ impl ClassMethodInfo {
             "cannot have more default arguments than arguments"
         );
 
+        if crate::obj::EngineBitfield::is_set(method_flags, MethodFlags::VARARG) {
+            assert!(
+                ptrcall_func.is_none(),
+                "variadic method `{method_name}` cannot provide a ptrcall function"
+            );
+        }
+
         Self {
             class_name,
             method_name,
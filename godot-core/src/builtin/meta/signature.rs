@@ -19,6 +19,15 @@ use crate::obj::{GodotClass, InstanceId};
 // separate arguments and return values, so that a type can be used in function arguments even if it doesn't
 // implement `ToGodot`, and the other way around for return values.
 
+/// Tuple of parameters `(P0, P1, ...)`, used for typed dynamic calls and signals.
+///
+/// Implemented for tuples of up to 14 elements, each of which must implement `ToGodot + FromGodot + Debug`.
+pub trait ParamTuple: Sized + 'static {
+    /// Signature `((), P0, P1, ...)` with these parameters.
+    #[doc(hidden)]
+    type Signature: VarcallSignatureTuple<Params = Self> + 'static;
+}
+
 #[doc(hidden)]
 pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
     const PARAM_COUNT: usize;
@@ -27,19 +36,48 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
     fn param_info(index: usize, param_name: &str) -> Option<MethodParamOrReturnInfo>;
     fn return_info() -> Option<MethodParamOrReturnInfo>;
 
+    /// Converts typed parameters into `Variant`s, e.g. to pass them as signal arguments.
+    fn params_to_variants(args: Self::Params) -> Vec<Variant>;
+
+    /// Converts dynamically passed arguments (e.g. received from a signal) into typed parameters.
+    fn params_from_variants(
+        call_ctx: &CallContext,
+        args: &[&Variant],
+    ) -> Result<Self::Params, CallError>;
+
+    /// Inbound varcall from Godot.
+    ///
+    /// If fewer than `PARAM_COUNT` arguments are passed, the missing trailing ones are taken from `default_values`, which holds the
+    /// defaults of the last `default_values.len()` parameters.
+    //
     // TODO(uninit) - can we use this for varcall/ptrcall?
     // ret: sys::GDExtensionUninitializedVariantPtr
     // ret: sys::GDExtensionUninitializedTypePtr
+    #[allow(clippy::too_many_arguments)]
     unsafe fn in_varcall(
         instance_ptr: sys::GDExtensionClassInstancePtr,
         call_ctx: &CallContext,
         args_ptr: *const sys::GDExtensionConstVariantPtr,
         arg_count: i64,
+        default_values: &[Variant],
         ret: sys::GDExtensionVariantPtr,
         err: *mut sys::GDExtensionCallError,
         func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
     ) -> Result<(), CallError>;
 
+    /// Inbound varcall from Godot to a variadic method.
+    ///
+    /// The first `PARAM_COUNT` arguments are converted to `Params`; all further arguments are passed to `func` as a slice.
+    unsafe fn in_varcall_variadic(
+        instance_ptr: sys::GDExtensionClassInstancePtr,
+        call_ctx: &CallContext,
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: i64,
+        ret: sys::GDExtensionVariantPtr,
+        err: *mut sys::GDExtensionCallError,
+        func: fn(sys::GDExtensionClassInstancePtr, Self::Params, &[&Variant]) -> Self::Ret,
+    ) -> Result<(), CallError>;
+
     unsafe fn out_class_varcall(
         method_bind: ClassMethodBind,
         // Separate parameters to reduce tokens in generated class API.
@@ -139,6 +177,15 @@ macro_rules! impl_varcall_signature_for_tuple {
         $R:ident
         $(, ($pn:ident, $n:tt) : $Pn:ident)* // $n cannot be literal if substituted as tuple index .0
     ) => {
+        impl<$($Pn,)*> ParamTuple for ($($Pn,)*)
+            where
+                $(
+                    $Pn: ToGodot + FromGodot + Debug + 'static,
+                )*
+        {
+            type Signature = ((), $($Pn,)*);
+        }
+
         #[allow(unused_variables)]
         impl<$R, $($Pn,)*> VarcallSignatureTuple for ($R, $($Pn,)*)
             where
@@ -164,6 +211,29 @@ macro_rules! impl_varcall_signature_for_tuple {
                 $R::Via::return_info()
             }
 
+            #[inline]
+            fn params_to_variants(($($pn,)*): Self::Params) -> Vec<Variant> {
+                vec![
+                    $(
+                        $pn.to_variant(),
+                    )*
+                ]
+            }
+
+            #[inline]
+            fn params_from_variants(
+                call_ctx: &CallContext,
+                args: &[&Variant],
+            ) -> Result<Self::Params, CallError> {
+                CallError::check_arg_count(call_ctx, args.len(), 0, $PARAM_COUNT)?;
+
+                let params = ($(
+                    variant_arg::<$Pn>(args[$n], $n, call_ctx)?,
+                )*);
+
+                Ok(params)
+            }
+
             #[inline]
             fn param_property_info(index: usize, param_name: &str) -> PropertyInfo {
                 match index {
@@ -180,16 +250,27 @@ macro_rules! impl_varcall_signature_for_tuple {
                 call_ctx: &CallContext,
                 args_ptr: *const sys::GDExtensionConstVariantPtr,
                 arg_count: i64,
+                default_values: &[Variant],
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
                 func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
             ) -> Result<(), CallError> {
                 //$crate::out!("in_varcall: {call_ctx}");
-                CallError::check_arg_count(call_ctx, arg_count as usize, $PARAM_COUNT)?;
+                let arg_count = arg_count as usize;
+                CallError::check_arg_count(call_ctx, arg_count, default_values.len(), $PARAM_COUNT)?;
 
                 #[cfg(feature = "trace")]
                 trace::push(true, false, &call_ctx);
 
+                // Must outlive the argument conversions below.
+                let filled_args;
+                let args_ptr = if arg_count < Self::PARAM_COUNT {
+                    filled_args = fill_default_args(args_ptr, arg_count, Self::PARAM_COUNT, default_values);
+                    filled_args.as_ptr()
+                } else {
+                    args_ptr
+                };
+
                 let args = ($(
                     unsafe { varcall_arg::<$Pn, $n>(args_ptr, call_ctx)? },
                 )*) ;
@@ -199,6 +280,36 @@ macro_rules! impl_varcall_signature_for_tuple {
                 Ok(())
             }
 
+            #[inline]
+            unsafe fn in_varcall_variadic(
+                instance_ptr: sys::GDExtensionClassInstancePtr,
+                call_ctx: &CallContext,
+                args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: i64,
+                ret: sys::GDExtensionVariantPtr,
+                err: *mut sys::GDExtensionCallError,
+                func: fn(sys::GDExtensionClassInstancePtr, Self::Params, &[&Variant]) -> Self::Ret,
+            ) -> Result<(), CallError> {
+                //$crate::out!("in_varcall_variadic: {call_ctx}");
+                let arg_count = arg_count as usize;
+                CallError::check_variadic_arg_count(call_ctx, arg_count, $PARAM_COUNT)?;
+
+                #[cfg(feature = "trace")]
+                trace::push(true, false, &call_ctx);
+
+                let args = ($(
+                    unsafe { varcall_arg::<$Pn, $n>(args_ptr, call_ctx)? },
+                )*) ;
+
+                let varargs: Vec<&Variant> = (Self::PARAM_COUNT..arg_count)
+                    .map(|i| unsafe { Variant::borrow_var_sys(*args_ptr.add(i)) })
+                    .collect();
+
+                let rust_result = func(instance_ptr, args, &varargs);
+                varcall_return::<$R>(rust_result, ret, err);
+                Ok(())
+            }
+
             #[inline]
             unsafe fn out_class_varcall(
                 method_bind: ClassMethodBind,
@@ -477,8 +588,43 @@ unsafe fn varcall_arg<P: FromGodot, const N: isize>(
 ) -> Result<P, CallError> {
     let variant_ref = Variant::borrow_var_sys(*args_ptr.offset(N));
 
-    P::try_from_variant(variant_ref)
-        .map_err(|err| CallError::failed_param_conversion::<P>(call_ctx, N, err))
+    variant_arg::<P>(variant_ref, N, call_ctx)
+}
+
+/// Returns pointers to `param_count` arguments, where arguments missing at the end are taken from the end of `default_values`.
+///
+/// # Safety
+/// `args_ptr` must point to `arg_count` valid argument pointers (it may be null if `arg_count` is 0).
+unsafe fn fill_default_args(
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: usize,
+    param_count: usize,
+    default_values: &[Variant],
+) -> Vec<sys::GDExtensionConstVariantPtr> {
+    let explicit_args = if arg_count == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(args_ptr, arg_count)
+    };
+
+    let missing_count = param_count - arg_count;
+    let defaults = &default_values[default_values.len() - missing_count..];
+
+    explicit_args
+        .iter()
+        .copied()
+        .chain(defaults.iter().map(Variant::var_sys))
+        .collect()
+}
+
+/// Convert a borrowed `Variant` argument at position `index` into a value of type `P`.
+fn variant_arg<P: FromGodot>(
+    variant: &Variant,
+    index: isize,
+    call_ctx: &CallContext,
+) -> Result<P, CallError> {
+    P::try_from_variant(variant)
+        .map_err(|err| CallError::failed_param_conversion::<P>(call_ctx, index, err))
 }
 
 /// Moves `ret_val` into `ret`.
@@ -46,7 +46,7 @@ pub mod __prelude_reexport {
     pub use callable::*;
     pub use color::*;
     pub use color_hsv::*;
-    pub use dictionary_inner::Dictionary;
+    pub use dictionary_inner::{Dictionary, VariantDictionary};
     pub use packed_array::*;
     pub use plane::*;
     pub use projection::*;
@@ -66,6 +66,10 @@ pub mod __prelude_reexport {
 }
 
 pub use __prelude_reexport::*;
+pub use typed_signal::{SignalReceiver, TypedSignal};
+
+#[doc(hidden)]
+pub use typed_signal::SignalObject;
 
 /// Meta-information about variant types, properties and class names.
 pub mod meta;
@@ -112,6 +116,7 @@ mod signal;
 mod string;
 mod transform2d;
 mod transform3d;
+mod typed_signal;
 mod variant;
 mod vectors;
 
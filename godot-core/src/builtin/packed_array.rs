@@ -492,6 +492,16 @@ macro_rules! impl_packed_array {
                     $crate::property::PropertyHintInfo::with_hint_none(<$PackedArray as $crate::builtin::meta::GodotType>::godot_type_name())
                 }
             }
+
+            fn element_variant_type() -> Option<$crate::builtin::VariantType> {
+                use $crate::builtin::meta::{GodotConvert, GodotType};
+
+                Some(<<$Element as GodotConvert>::Via as GodotType>::Ffi::variant_type())
+            }
+        }
+
+        impl $crate::property::ElementExport for $PackedArray {
+            type Element = $Element;
         }
     }
 }
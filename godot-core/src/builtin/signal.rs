@@ -12,12 +12,19 @@ use godot_ffi as sys;
 
 use super::meta::{impl_godot_as_self, FromGodot, GodotType};
 use crate::builtin::meta::ToGodot;
-use crate::builtin::{inner, Array, Callable, Dictionary, StringName, Variant};
+use crate::builtin::{inner, Array, Callable, StringName, Variant, VariantDictionary};
 use crate::engine::{global::Error, Object};
 use crate::obj::bounds::DynMemory;
 use crate::obj::{Bounds, Gd, GodotClass, InstanceId};
 use sys::{ffi_methods, GodotFfi};
 
+#[cfg(since_api = "4.2")]
+use crate::builtin::meta::ParamTuple;
+#[cfg(since_api = "4.2")]
+use crate::builtin::typed_signal::{make_receiver_callable, SignalReceiver};
+#[cfg(since_api = "4.2")]
+use crate::task::SignalFuture;
+
 /// A `Signal` represents a signal of an Object instance in Godot.
 ///
 /// Signals are composed of a reference to an `Object` and the name of the signal on this object.
@@ -76,6 +83,80 @@ impl Signal {
         Error::from_godot(error as i32)
     }
 
+    /// Connects a Rust function or closure with typed parameters to this signal.
+    ///
+    /// Signal arguments are converted to the closure's parameter types via [`FromGodot`]. If conversion fails, an error is printed and
+    /// the closure is not invoked.
+    ///
+    /// The connection lasts as long as the returned [`ConnectionGuard`] lives. Use [`ConnectionGuard::forget()`] to keep it until the
+    /// signal's object is freed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn connect(node: &Gd<Node>) {
+    /// let signal = Signal::from_object_signal(node, "child_order_changed");
+    /// let guard = signal.connect_fn(|| godot_print!("children reordered"));
+    ///
+    /// // ...
+    /// drop(guard); // disconnects
+    /// # }
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn connect_fn<Ps, F>(&self, function: F) -> ConnectionGuard
+    where
+        Ps: ParamTuple,
+        F: SignalReceiver<Ps> + Send + Sync,
+    {
+        self.connect_receiver(None, function)
+    }
+
+    /// Like [`connect_fn()`][Self::connect_fn], but the connection is additionally tied to the lifetime of the object `owner`.
+    ///
+    /// Once `owner` is freed, the function is no longer invoked and Godot removes the connection. This is typically used when the
+    /// closure refers to `owner`, e.g. through [`Gd::from_instance_id()`].
+    #[cfg(since_api = "4.2")]
+    pub fn connect_fn_tracked<Ps, F>(&self, owner: InstanceId, function: F) -> ConnectionGuard
+    where
+        Ps: ParamTuple,
+        F: SignalReceiver<Ps> + Send + Sync,
+    {
+        self.connect_receiver(Some(owner), function)
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn connect_receiver<Ps, F>(&self, owner: Option<InstanceId>, function: F) -> ConnectionGuard
+    where
+        Ps: ParamTuple,
+        F: SignalReceiver<Ps> + Send + Sync,
+    {
+        let class_name = self
+            .object()
+            .map(|object| object.get_class().to_string())
+            .unwrap_or_else(|| String::from("<null>"));
+
+        let callable = make_receiver_callable::<Ps::Signature, F>(
+            class_name,
+            self.name().to_string(),
+            owner,
+            function,
+        );
+
+        // Connecting a new custom callable cannot fail due to duplicates; other errors (e.g. unknown signal) are printed by Godot.
+        self.connect(callable.clone(), 0);
+
+        ConnectionGuard::new(self.clone(), callable)
+    }
+
+    /// Returns a future that resolves with the arguments of the next emission of this signal.
+    ///
+    /// The parameter tuple `R` is usually inferred from the context, e.g. `let (amount,): (i64,) = signal.to_future().await;`.
+    /// See [`SignalFuture`] for cancellation semantics.
+    #[cfg(since_api = "4.2")]
+    pub fn to_future<R: ParamTuple>(&self) -> SignalFuture<R> {
+        SignalFuture::new(self.clone())
+    }
+
     /// Disconnects this signal from the specified [`Callable`].
     ///
     /// If the connection does not exist, generates an error. Use [`Self::is_connected`] to make sure that the connection exists.
@@ -102,7 +183,7 @@ impl Signal {
     ///  - `flags` is a combination of [`ConnectFlags`](crate::engine::object::ConnectFlags).
     ///
     /// _Godot equivalent: `get_connections`_
-    pub fn connections(&self) -> Array<Dictionary> {
+    pub fn connections(&self) -> Array<VariantDictionary> {
         self.as_inner()
             .get_connections()
             .iter_shared()
@@ -204,3 +285,90 @@ impl fmt::Display for Signal {
         write!(f, "{}", self.to_variant())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Connection between a signal and a callable, which is disconnected when dropped.
+///
+/// Returned by [`Signal::connect_fn()`] and the guarded connect methods of [`TypedSignal`][crate::builtin::TypedSignal].
+/// Store the guard for as long as the connection should stay alive, e.g. in a field of the receiving object.
+///
+/// Dropping the guard after the signal's object has been freed is fine; there is nothing left to disconnect then.
+#[cfg(since_api = "4.2")]
+#[must_use = "the connection is removed when the guard is dropped; use `forget()` to keep it"]
+pub struct ConnectionGuard {
+    signal: Signal,
+    callable: Option<Callable>,
+}
+
+#[cfg(since_api = "4.2")]
+impl ConnectionGuard {
+    pub(crate) fn new(signal: Signal, callable: Callable) -> Self {
+        Self {
+            signal,
+            callable: Some(callable),
+        }
+    }
+
+    /// The signal that is connected.
+    pub fn signal(&self) -> &Signal {
+        &self.signal
+    }
+
+    /// The callable connected to the signal.
+    pub fn callable(&self) -> &Callable {
+        self.callable
+            .as_ref()
+            .expect("callable only taken on disconnect/forget")
+    }
+
+    /// Returns `true` if the connection still exists.
+    ///
+    /// This is `false` if the signal's object or a tracked owner has been freed in the meantime.
+    pub fn is_connected(&self) -> bool {
+        match &self.callable {
+            Some(callable) => !self.signal.is_null() && self.signal.is_connected(callable.clone()),
+            None => false,
+        }
+    }
+
+    /// Disconnects immediately. Equivalent to dropping the guard.
+    pub fn disconnect(mut self) {
+        self.disconnect_inner();
+    }
+
+    /// Consumes the guard without disconnecting.
+    ///
+    /// The connection then stays alive until it is disconnected manually or the signal's object (or tracked owner) is freed.
+    pub fn forget(mut self) {
+        self.callable = None;
+    }
+
+    fn disconnect_inner(&mut self) {
+        let Some(callable) = self.callable.take() else {
+            return;
+        };
+
+        // Godot already removed the connection if the object or tracked owner is gone.
+        if !self.signal.is_null() && self.signal.is_connected(callable.clone()) {
+            self.signal.disconnect(callable);
+        }
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.disconnect_inner();
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl fmt::Debug for ConnectionGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionGuard")
+            .field("signal", &self.signal)
+            .field("connected", &self.is_connected())
+            .finish()
+    }
+}
@@ -0,0 +1,369 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::meta::VarcallSignatureTuple;
+use crate::builtin::{Signal, StringName};
+use crate::obj::{Gd, GodotClass, WithBaseField};
+
+#[cfg(since_api = "4.2")]
+use crate::builtin::meta::{CallContext, ParamTuple};
+#[cfg(since_api = "4.2")]
+use crate::builtin::{Callable, ConnectionGuard, Variant};
+#[cfg(since_api = "4.2")]
+use crate::obj::InstanceId;
+#[cfg(since_api = "4.2")]
+use crate::task::SignalFuture;
+
+/// Object on which a [`TypedSignal`] is emitted or connected.
+///
+/// Can be obtained in two ways:
+/// - From inside the class, through `self.signals()`. Emitting then makes the Rust instance inaccessible for the duration of the call,
+///   like [`WithBaseField::base_mut()`], so that connected handlers can call back into the same object.
+/// - From outside the class, through [`Gd::signals()`].
+#[doc(hidden)]
+pub struct SignalObject<'c, C: GodotClass> {
+    gd: Gd<C>,
+    instance: Option<InstanceAccess<'c, C>>,
+}
+
+struct InstanceAccess<'c, C> {
+    instance: &'c mut C,
+
+    /// Runs the closure while the instance is made inaccessible (re-entrant access through `base_mut()`).
+    run_reentrant: fn(&mut C, &mut dyn FnMut()),
+}
+
+impl<'c, C: GodotClass> SignalObject<'c, C> {
+    /// Signal object for `self.signals()` inside the class.
+    pub fn from_instance(instance: &'c mut C) -> Self
+    where
+        C: WithBaseField,
+    {
+        Self {
+            gd: instance.to_gd(),
+            instance: Some(InstanceAccess {
+                instance,
+                run_reentrant: |instance, f| {
+                    let _guard = instance.base_mut();
+                    f();
+                },
+            }),
+        }
+    }
+
+    /// Signal object for `gd.signals()` outside the class.
+    pub fn from_gd(gd: Gd<C>) -> Self {
+        Self { gd, instance: None }
+    }
+
+    /// Shortens the lifetime, so that multiple signals can be accessed from the same collection.
+    pub fn reborrow(&mut self) -> SignalObject<'_, C> {
+        SignalObject {
+            gd: self.gd.clone(),
+            instance: self.instance.as_mut().map(|access| InstanceAccess {
+                instance: &mut *access.instance,
+                run_reentrant: access.run_reentrant,
+            }),
+        }
+    }
+
+    fn with_gd(&mut self, f: impl FnOnce(&Gd<C>)) {
+        let gd = &self.gd;
+
+        match &mut self.instance {
+            Some(access) => {
+                let mut f = Some(f);
+                (access.run_reentrant)(access.instance, &mut || {
+                    let f = f.take().expect("run_reentrant calls closure at most once");
+                    f(gd)
+                });
+            }
+            None => f(gd),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Type-safe version of a Godot signal, declared with `#[signal]` on a user class or provided by an engine class.
+///
+/// `C` is the class of the object on which the signal is accessed, `Sig` is the signature tuple `((), P0, P1, ...)` with parameter types `P0`, `P1`, ...
+///
+/// You typically don't name this type directly. Instead, it is accessed through the signal collection of a class. For user classes,
+/// this collection is generated from `#[signal]` declarations inside `#[godot_api]`; engine classes provide one for their built-in
+/// signals (e.g. `timer.signals().timeout()`).
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node)]
+/// struct Player {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn hit(damage: i64);
+///
+///     #[func]
+///     fn take_damage(&mut self) {
+///         // Arguments are checked at compile time.
+///         self.signals().hit().emit(42);
+///     }
+/// }
+///
+/// fn connect_player(player: &Gd<Player>) {
+///     player.signals().hit().connect(|damage: i64| {
+///         godot_print!("Player took {damage} damage");
+///     });
+/// }
+/// ```
+pub struct TypedSignal<'c, C: GodotClass, Sig> {
+    object: SignalObject<'c, C>,
+    name: &'static str,
+    _signature: PhantomData<fn(Sig)>,
+}
+
+impl<'c, C, Sig> TypedSignal<'c, C, Sig>
+where
+    C: GodotClass,
+    Sig: VarcallSignatureTuple + 'static,
+{
+    #[doc(hidden)]
+    pub fn new(object: SignalObject<'c, C>, name: &'static str) -> Self {
+        Self {
+            object,
+            name,
+            _signature: PhantomData,
+        }
+    }
+
+    /// Name of the signal.
+    pub fn name(&self) -> StringName {
+        StringName::from(self.name)
+    }
+
+    /// Emits the signal with the given arguments, passed as a tuple.
+    ///
+    /// [`emit()`][Self::emit] accepts the arguments individually instead.
+    pub fn emit_tuple(&mut self, args: Sig::Params) {
+        let name = self.name;
+        let args = Sig::params_to_variants(args);
+
+        self.object.with_gd(|gd| {
+            Signal::from_object_signal(gd, name).emit(&args);
+        });
+    }
+
+    /// Connects a Rust function or closure to this signal.
+    ///
+    /// The function receives the signal arguments with their declared types. If Godot emits the signal with arguments that cannot
+    /// be converted (e.g. through an untyped `emit_signal()` call), an error is printed and the function is not invoked.
+    ///
+    /// The connection stays alive until the object is freed. To disconnect earlier, use [`connect_guarded()`][Self::connect_guarded].
+    #[cfg(since_api = "4.2")]
+    pub fn connect<F>(&mut self, function: F)
+    where
+        F: SignalReceiver<Sig::Params> + Send + Sync,
+    {
+        let callable = self.make_callable(None, function);
+        self.connect_callable(callable);
+    }
+
+    /// Connects a Rust function or closure, returning a guard that disconnects it when dropped.
+    ///
+    /// See [`connect()`][Self::connect] for how arguments are passed.
+    #[cfg(since_api = "4.2")]
+    pub fn connect_guarded<F>(&mut self, function: F) -> ConnectionGuard
+    where
+        F: SignalReceiver<Sig::Params> + Send + Sync,
+    {
+        let callable = self.make_callable(None, function);
+        self.connect_callable(callable.clone());
+
+        ConnectionGuard::new(self.to_untyped(), callable)
+    }
+
+    /// Like [`connect_guarded()`][Self::connect_guarded], but the connection is additionally removed once the object `owner` is freed.
+    ///
+    /// Use this when the function refers to another object (typically the receiver), so that it is not invoked after that object
+    /// has been destroyed.
+    #[cfg(since_api = "4.2")]
+    pub fn connect_tracked<F>(&mut self, owner: InstanceId, function: F) -> ConnectionGuard
+    where
+        F: SignalReceiver<Sig::Params> + Send + Sync,
+    {
+        let callable = self.make_callable(Some(owner), function);
+        self.connect_callable(callable.clone());
+
+        ConnectionGuard::new(self.to_untyped(), callable)
+    }
+
+    /// Connects an arbitrary callable to this signal.
+    ///
+    /// The callable's signature is not checked; prefer [`connect()`][Self::connect] where possible.
+    #[cfg(since_api = "4.2")]
+    pub fn connect_callable(&mut self, callable: Callable) {
+        let name = self.name;
+
+        // Connecting can only fail if the same callable is connected twice, which Godot already reports.
+        self.object.with_gd(|gd| {
+            Signal::from_object_signal(gd, name).connect(callable, 0);
+        });
+    }
+
+    /// Returns a future that resolves with the arguments of the next emission of this signal.
+    ///
+    /// Must be awaited inside a task started with [`godot::task::spawn()`][crate::task::spawn]. See [`SignalFuture`] for details.
+    #[cfg(since_api = "4.2")]
+    pub fn to_future(&self) -> SignalFuture<Sig::Params>
+    where
+        Sig::Params: ParamTuple,
+    {
+        SignalFuture::new(self.to_untyped())
+    }
+
+    /// Returns the untyped [`Signal`] for this object and signal name.
+    pub fn to_untyped(&self) -> Signal {
+        Signal::from_object_signal(&self.object.gd, self.name)
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn make_callable<F>(&self, owner: Option<InstanceId>, function: F) -> Callable
+    where
+        F: SignalReceiver<Sig::Params> + Send + Sync,
+    {
+        make_receiver_callable::<Sig, F>(
+            C::class_name().to_string(),
+            self.name.to_string(),
+            owner,
+            function,
+        )
+    }
+}
+
+macro_rules! impl_typed_signal_emit {
+    ($($Pn:ident $pn:ident),*) => {
+        impl<C: GodotClass, $($Pn,)*> TypedSignal<'_, C, ((), $($Pn,)*)>
+        where
+            ((), $($Pn,)*): VarcallSignatureTuple<Params = ($($Pn,)*)> + 'static,
+        {
+            /// Emits the signal with the given arguments.
+            pub fn emit(&mut self, $($pn: $Pn),*) {
+                self.emit_tuple(($($pn,)*))
+            }
+        }
+    };
+}
+
+impl_typed_signal_emit!();
+impl_typed_signal_emit!(P0 p0);
+impl_typed_signal_emit!(P0 p0, P1 p1);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2, P3 p3);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10, P11 p11);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10, P11 p11, P12 p12);
+impl_typed_signal_emit!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10, P11 p11, P12 p12, P13 p13);
+
+impl<C: GodotClass, Sig> fmt::Debug for TypedSignal<'_, C, Sig> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedSignal")
+            .field("name", &self.name)
+            .field("object", &self.object.gd)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Function or closure that can be connected to a [`TypedSignal`] with parameters `Ps`.
+///
+/// Implemented for all `FnMut` closures whose parameters match the tuple `Ps`, e.g. `FnMut(i64, GString)` for `Ps = (i64, GString)`.
+pub trait SignalReceiver<Ps>: 'static {
+    /// Invokes the function with the parameter tuple unpacked.
+    fn call(&mut self, params: Ps);
+}
+
+/// Creates a callable that converts the signal arguments to the parameters of `Sig` and forwards them to `function`.
+///
+/// If `owner` is given, the function is no longer invoked once that object is freed.
+#[cfg(since_api = "4.2")]
+pub(crate) fn make_receiver_callable<Sig, F>(
+    class_name: String,
+    signal_name: String,
+    owner: Option<InstanceId>,
+    function: F,
+) -> Callable
+where
+    Sig: VarcallSignatureTuple + 'static,
+    F: SignalReceiver<Sig::Params> + Send + Sync,
+{
+    let callable_name = format!("{class_name}::{signal_name} (typed signal handler)");
+
+    let mut function = function;
+    Callable::from_fn_with_owner(callable_name, owner, move |args: &[&Variant]| {
+        // Godot removes the connection when the owner is freed; this additionally covers emissions during the owner's destruction.
+        if let Some(owner) = owner {
+            if !crate::engine::utilities::is_instance_id_valid(owner.to_i64()) {
+                return Ok(Variant::nil());
+            }
+        }
+
+        let call_ctx = CallContext::func(&class_name, &signal_name);
+        match Sig::params_from_variants(&call_ctx, args) {
+            Ok(params) => {
+                function.call(params);
+                Ok(Variant::nil())
+            }
+            Err(err) => {
+                crate::godot_error!("{err}");
+                Err(())
+            }
+        }
+    })
+}
+
+macro_rules! impl_signal_receiver {
+    ($($Pn:ident $pn:ident),*) => {
+        impl<F, $($Pn,)*> SignalReceiver<($($Pn,)*)> for F
+        where
+            F: FnMut($($Pn),*) + 'static,
+        {
+            fn call(&mut self, ($($pn,)*): ($($Pn,)*)) {
+                self($($pn),*)
+            }
+        }
+    };
+}
+
+impl_signal_receiver!();
+impl_signal_receiver!(P0 p0);
+impl_signal_receiver!(P0 p0, P1 p1);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2, P3 p3);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10, P11 p11);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10, P11 p11, P12 p12);
+impl_signal_receiver!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10, P11 p11, P12 p12, P13 p13);
@@ -112,6 +112,21 @@ impl Variant {
     }
 
     fn call_inner(&self, method: StringName, args: &[Variant]) -> Variant {
+        match self.try_call_inner(method, args) {
+            Ok(result) => result,
+            Err(error) => {
+                let arg_types: Vec<_> = args.iter().map(Variant::get_type).collect();
+                sys::panic_call_error(&error, "call", &arg_types);
+            }
+        }
+    }
+
+    /// Like [`call()`][Self::call], but returns Godot's call error instead of panicking.
+    pub(crate) fn try_call_inner(
+        &self,
+        method: StringName,
+        args: &[Variant],
+    ) -> Result<Variant, sys::GDExtensionCallError> {
         let args_sys: Vec<_> = args.iter().map(|v| v.var_sys()).collect();
         let mut error = sys::default_call_error();
 
@@ -128,11 +143,11 @@ impl Variant {
             })
         };
 
-        if error.error != sys::GDEXTENSION_CALL_OK {
-            let arg_types: Vec<_> = args.iter().map(Variant::get_type).collect();
-            sys::panic_call_error(&error, "call", &arg_types);
+        if error.error == sys::GDEXTENSION_CALL_OK {
+            Ok(result)
+        } else {
+            Err(error)
         }
-        result
     }
 
     /// Evaluates an expression using a GDScript operator.
@@ -145,6 +145,12 @@ fn gdext_on_level_init(level: InitLevel) {
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
 fn gdext_on_level_deinit(level: InitLevel) {
+    // Pending tasks may hold objects and signal connections, which must be released while the engine is still alive.
+    #[cfg(since_api = "4.2")]
+    if level == InitLevel::Scene {
+        crate::task::cleanup();
+    }
+
     crate::unregister_classes(level);
 
     if level == InitLevel::Core {
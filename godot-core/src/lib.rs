@@ -14,6 +14,7 @@ pub mod init;
 pub mod log;
 pub mod obj;
 pub mod property;
+pub mod task;
 
 #[doc(hidden)]
 #[path = "deprecated.rs"]
@@ -27,18 +27,29 @@ macro_rules! inner_godot_msg {
     // FIXME expr needs to be parenthesised, see usages
     ($godot_fn:ident; $fmt:literal $(, $args:expr)* $(,)?) => {
     //($($args:tt),* $(,)?) => {
+        $crate::inner_godot_msg!(@c_str $godot_fn, &format!("{}\0", $crate::inner_function!()), concat!(file!(), "\0"), line!(); $fmt $(, $args)*)
+    };
+
+    // Explicit source location, for messages reported on behalf of user code (e.g. errors returned by #[func] methods).
+    ($godot_fn:ident @ $function:expr, $file:expr, $line:expr; $fmt:literal $(, $args:expr)* $(,)?) => {
+        $crate::inner_godot_msg!(@c_str $godot_fn, &format!("{}\0", $function), &format!("{}\0", $file), $line; $fmt $(, $args)*)
+    };
+
+    // Function and file names must be nul-terminated. They are only evaluated if the engine is loaded.
+    (@c_str $godot_fn:ident, $function:expr, $file:expr, $line:expr; $fmt:literal $(, $args:expr)* $(,)?) => {
         unsafe {
             let msg = format!("{}\0", format_args!($fmt $(, $args)*));
             // assert!(msg.is_ascii(), "godot_error: message must be ASCII");
 
             // Check whether engine is loaded, otherwise fall back to stderr.
             if $crate::sys::is_initialized() {
-                let function = format!("{}\0", $crate::inner_function!());
+                let function = $function;
+                let file = $file;
                 $crate::sys::interface_fn!($godot_fn)(
                     $crate::sys::c_str_from_str(&msg),
-                    $crate::sys::c_str_from_str(&function),
-                    $crate::sys::c_str_from_str(concat!(file!(), "\0")),
-                    line!() as i32,
+                    $crate::sys::c_str_from_str(function),
+                    $crate::sys::c_str_from_str(file),
+                    $line as i32,
                     false as $crate::sys::GDExtensionBool, // whether to create a toast notification in editor
                 );
             } else {
@@ -0,0 +1,366 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::{type_name, TypeId};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::ops::{Deref, DerefMut};
+
+use crate::builtin::meta::{ClassName, ConvertError, FromGodot, GodotConvert, ToGodot};
+use crate::engine;
+use crate::obj::{bounds, AsDyn, Bounds, DynGdMut, DynGdRef, Gd, GodotClass, Inherits};
+use crate::property::{Export, PropertyHintInfo, Var};
+
+/// Smart pointer to a Godot object, which additionally gives access to a Rust trait object `D`.
+///
+/// `DynGd<T, D>` behaves like `Gd<T>` (which it dereferences to), but also allows binding the object as `&dyn Trait` or `&mut dyn Trait`
+/// through [`dyn_bind()`][Self::dyn_bind] and [`dyn_bind_mut()`][Self::dyn_bind_mut]. `T` is typically an engine class such as `Node`,
+/// while the object's dynamic type is a user class implementing the trait.
+///
+/// # Registration
+/// Trait implementations become available to `DynGd` by annotating them with [`#[godot_dyn]`](../register/attr.godot_dyn.html):
+/// ```no_run
+/// # use godot::prelude::*;
+/// trait Damageable {
+///     fn take_damage(&mut self, amount: i32);
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node3D)]
+/// struct Enemy {
+///     health: i32,
+/// }
+///
+/// #[godot_dyn]
+/// impl Damageable for Enemy {
+///     fn take_damage(&mut self, amount: i32) {
+///         self.health -= amount;
+///     }
+/// }
+///
+/// fn on_collision(node: Gd<Node3D>) {
+///     // Runtime lookup: succeeds if the object's class implements Damageable.
+///     if let Ok(mut target) = DynGd::<Node3D, dyn Damageable>::try_from_gd(node) {
+///         target.dyn_bind_mut().take_damage(10);
+///     }
+/// }
+/// ```
+///
+/// # Conversions
+/// `DynGd<T, D>` is converted to and from Godot like `Gd<T>`, so it can be used as a `#[func]` parameter or return type, as well as for
+/// `#[var]` and `#[export]` fields. Converting an object whose class does not implement `D` fails, just like converting an object of an
+/// unrelated class to `Gd<T>`. The editor does not know about Rust traits, so for exported fields, it offers all objects of class `T`.
+pub struct DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    obj: Gd<T>,
+
+    /// Same object, typed with its concrete user class.
+    erased_obj: Box<dyn ErasedGd<D>>,
+}
+
+impl<T, D> DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    /// Creates a `DynGd` from an object whose concrete class is known to implement `D`.
+    ///
+    /// See also [`Gd::into_dyn()`].
+    pub fn from_gd<Derived>(gd: Gd<Derived>) -> Self
+    where
+        Derived: Inherits<T> + AsDyn<D>,
+    {
+        let erased_obj = Box::new(gd.clone());
+
+        Self {
+            obj: gd.upcast(),
+            erased_obj,
+        }
+    }
+
+    /// Looks up whether the object's dynamic class implements `D`, and if so, creates a `DynGd` from it.
+    ///
+    /// Only trait implementations registered with `#[godot_dyn]` are found. Returns an error if the object's class has no such
+    /// implementation, or if the object is dead.
+    pub fn try_from_gd(gd: Gd<T>) -> Result<Self, ConvertError> {
+        let object = gd
+            .clone()
+            .owned_cast::<engine::Object>()
+            .unwrap_or_else(|_| unreachable!("every class inherits Object"));
+
+        if !object.is_instance_valid() {
+            return Err(ConvertError::new("cannot create DynGd from dead object"));
+        }
+
+        let class_name = ClassName::from_dynamic(&object.get_class().to_string());
+        let Some(dyn_trait_impl) =
+            crate::registry::find_dyn_trait_impl(class_name, TypeId::of::<D>())
+        else {
+            return Err(ConvertError::new(format!(
+                "class `{class_name}` does not implement trait `{trait_name}` (or it is not registered with #[godot_dyn])",
+                trait_name = type_name::<D>(),
+            )));
+        };
+
+        let erased_obj = *dyn_trait_impl
+            .dynify(object)
+            .downcast::<Box<dyn ErasedGd<D>>>()
+            .expect("DynTraitImpl registered for matching trait");
+
+        Ok(Self {
+            obj: gd,
+            erased_obj,
+        })
+    }
+
+    /// Hands out a guard for a shared borrow, through which the user instance can be read as `&D`.
+    ///
+    /// # Panics
+    /// Like [`Gd::bind()`], if the instance is currently bound mutably.
+    #[track_caller]
+    pub fn dyn_bind(&self) -> DynGdRef<D> {
+        self.erased_obj.dyn_bind()
+    }
+
+    /// Hands out a guard for an exclusive borrow, through which the user instance can be read and written as `&mut D`.
+    ///
+    /// # Panics
+    /// Like [`Gd::bind_mut()`], if the instance is currently bound.
+    #[track_caller]
+    pub fn dyn_bind_mut(&mut self) -> DynGdMut<D> {
+        self.erased_obj.dyn_bind_mut()
+    }
+
+    /// **Upcast** to a `DynGd` with a base class of `T`, keeping access to `D`.
+    pub fn upcast<Base>(self) -> DynGd<Base, D>
+    where
+        Base: GodotClass,
+        T: Inherits<Base>,
+    {
+        DynGd {
+            obj: self.obj.upcast(),
+            erased_obj: self.erased_obj,
+        }
+    }
+
+    /// Converts into a regular `Gd<T>`, discarding the trait object access.
+    pub fn into_gd(self) -> Gd<T> {
+        self.obj
+    }
+}
+
+impl<T> Gd<T>
+where
+    T: GodotClass + Bounds<Declarer = bounds::DeclUser>,
+{
+    /// Converts into a [`DynGd`], which gives access to this object through the trait object `D`.
+    ///
+    /// Unlike [`DynGd::try_from_gd()`], this is checked at compile time and does not need a runtime lookup.
+    pub fn into_dyn<D>(self) -> DynGd<T, D>
+    where
+        T: AsDyn<D>,
+        D: ?Sized + 'static,
+    {
+        DynGd::from_gd(self)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Trait impls
+
+impl<T, D> Deref for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    type Target = Gd<T>;
+
+    fn deref(&self) -> &Gd<T> {
+        &self.obj
+    }
+}
+
+impl<T, D> DerefMut for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn deref_mut(&mut self) -> &mut Gd<T> {
+        &mut self.obj
+    }
+}
+
+impl<T, D> Clone for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            obj: self.obj.clone(),
+            erased_obj: self.erased_obj.clone_box(),
+        }
+    }
+}
+
+impl<T, D> PartialEq for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    /// ⚠️ Returns whether two `DynGd` pointers point to the same object.
+    ///
+    /// # Panics
+    /// When `self` or `other` is dead.
+    fn eq(&self, other: &Self) -> bool {
+        self.obj == other.obj
+    }
+}
+
+impl<T, D> Eq for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+}
+
+impl<T, D> Display for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        engine::display_string(&self.obj, f)
+    }
+}
+
+impl<T, D> Debug for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        engine::debug_string(&self.obj, f, "DynGd")
+    }
+}
+
+impl<T, D> GodotConvert for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    type Via = Gd<T>;
+}
+
+impl<T, D> ToGodot for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn to_godot(&self) -> Self::Via {
+        self.obj.to_godot()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self.obj.into_godot()
+    }
+}
+
+impl<T, D> FromGodot for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from_gd(via)
+    }
+}
+
+impl<T, D> Var for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        // The editor or a script may assign any object of class T, including ones that do not implement D; keep the old value then.
+        match Self::try_from_godot(value) {
+            Ok(dyn_gd) => *self = dyn_gd,
+            Err(err) => crate::godot_error!(
+                "cannot assign object to DynGd<{}, {}> property: {err}",
+                T::class_name(),
+                type_name::<D>()
+            ),
+        }
+    }
+
+    fn property_hint() -> PropertyHintInfo {
+        <Gd<T> as Var>::property_hint()
+    }
+}
+
+impl<T, D> Export for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn default_export_info() -> PropertyHintInfo {
+        <Gd<T> as Export>::default_export_info()
+    }
+}
+
+// Like Gd, unwinding does not invalidate any invariants.
+impl<T, D> std::panic::UnwindSafe for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+}
+
+impl<T, D> std::panic::RefUnwindSafe for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type erasure
+
+/// Object typed with its concrete user class, which is hidden behind the trait object `D`.
+pub(crate) trait ErasedGd<D: ?Sized + 'static> {
+    #[track_caller]
+    fn dyn_bind(&self) -> DynGdRef<D>;
+    #[track_caller]
+    fn dyn_bind_mut(&mut self) -> DynGdMut<D>;
+    fn clone_box(&self) -> Box<dyn ErasedGd<D>>;
+}
+
+impl<T, D> ErasedGd<D> for Gd<T>
+where
+    T: AsDyn<D>,
+    D: ?Sized + 'static,
+{
+    #[track_caller]
+    fn dyn_bind(&self) -> DynGdRef<D> {
+        DynGdRef::from_guard::<T>(self.bind())
+    }
+
+    #[track_caller]
+    fn dyn_bind_mut(&mut self) -> DynGdMut<D> {
+        DynGdMut::from_guard::<T>(self.bind_mut())
+    }
+
+    fn clone_box(&self) -> Box<dyn ErasedGd<D>> {
+        Box::new(self.clone())
+    }
+}
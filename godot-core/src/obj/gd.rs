@@ -16,10 +16,11 @@ use crate::builtin::meta::{
     ArrayElement, CallContext, ConvertError, FromFfiError, FromGodot, GodotConvert, GodotType,
     ToGodot,
 };
-use crate::builtin::{Callable, NodePath, StringName, Variant};
+use crate::builtin::{Callable, NodePath, SignalObject, StringName, Variant};
 use crate::obj::raw::RawGd;
 use crate::obj::{
-    bounds, cap, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
+    bounds, cap, BindError, Bounds, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
+    WithSignals,
 };
 use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use crate::{callbacks, engine, out};
@@ -155,7 +156,12 @@ where
     /// * If another `Gd` smart pointer pointing to the same Rust instance has a live `GdMut` guard bound.
     /// * If there is an ongoing function call from GDScript to Rust, which currently holds a `&mut T`
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
+    ///
+    /// In Debug mode, the panic message lists where the existing borrows were taken. Borrows held by engine calls are not recorded in
+    /// user code: for `#[func]` methods, the site is the `#[godot_api]` impl block that declares them; for virtual methods such as
+    /// `ready()`, it is inside godot-rust (`registry/callbacks.rs`).
     // Note: possible names: write/read, hold/hold_mut, r/w, r/rw, ...
+    #[track_caller]
     pub fn bind(&self) -> GdRef<T> {
         self.raw.bind()
     }
@@ -171,9 +177,38 @@ where
     /// * If another `Gd` smart pointer pointing to the same Rust instance has a live `GdRef` or `GdMut` guard bound.
     /// * If there is an ongoing function call from GDScript to Rust, which currently holds a `&T` or `&mut T`
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
+    ///
+    /// In Debug mode, the panic message lists where the existing borrows were taken. See [`bind()`][Self::bind] for where borrows
+    /// of `#[func]` and virtual methods are recorded.
+    #[track_caller]
     pub fn bind_mut(&mut self) -> GdMut<T> {
         self.raw.bind_mut()
     }
+
+    /// Hands out a guard for a shared borrow, or an error if the instance is bound exclusively.
+    ///
+    /// Like [`bind()`][Self::bind], but does not panic if a `GdMut` guard or `&mut self` method holds the instance. The returned
+    /// [`BindError`] tells which borrows are in the way, including where they were taken in Debug mode. This is useful for code which
+    /// can run both standalone and re-entrantly.
+    ///
+    /// # Panics
+    /// If the object is dead or its dynamic type does not match `T` (these are bugs, not borrow conflicts).
+    #[track_caller]
+    pub fn try_bind(&self) -> Result<GdRef<T>, BindError> {
+        self.raw.try_bind()
+    }
+
+    /// Hands out a guard for an exclusive borrow, or an error if the instance is already bound.
+    ///
+    /// Like [`bind_mut()`][Self::bind_mut], but does not panic if other guards or `#[func]` calls hold the instance. The returned
+    /// [`BindError`] tells whether it is bound exclusively, or by how many shared borrows.
+    ///
+    /// # Panics
+    /// If the object is dead or its dynamic type does not match `T` (these are bugs, not borrow conflicts).
+    #[track_caller]
+    pub fn try_bind_mut(&mut self) -> Result<GdMut<T>, BindError> {
+        self.raw.try_bind_mut()
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -355,7 +390,7 @@ impl<T: GodotClass> Gd<T> {
     }
 
     /// Returns `Ok(cast_obj)` on success, `Err(self)` on error
-    fn owned_cast<U>(self) -> Result<Gd<U>, Self>
+    pub(crate) fn owned_cast<U>(self) -> Result<Gd<U>, Self>
     where
         U: GodotClass,
     {
@@ -422,6 +457,17 @@ impl<T: GodotClass> Gd<T> {
     pub fn callable<S: Into<StringName>>(&self, method_name: S) -> Callable {
         Callable::from_object_method(self, method_name)
     }
+
+    /// Returns the collection of type-safe signals declared by this class.
+    ///
+    /// Available for user classes with `#[signal]` declarations. From within the class, use
+    /// [`WithUserSignals::signals()`](crate::obj::WithUserSignals::signals) instead.
+    pub fn signals(&self) -> T::SignalCollection<'static>
+    where
+        T: WithSignals,
+    {
+        T::__signals_from_object(SignalObject::from_gd(self.clone()))
+    }
 }
 
 impl<T: GodotClass> Deref for Gd<T> {
@@ -651,14 +697,10 @@ impl<T: GodotClass> TypeStringHint for Gd<T> {
     fn type_string() -> String {
         use engine::global::PropertyHint;
 
-        match Self::default_export_info().hint {
-            hint @ (PropertyHint::RESOURCE_TYPE | PropertyHint::NODE_TYPE) => {
-                format!(
-                    "{}/{}:{}",
-                    VariantType::Object as i32,
-                    hint.ord(),
-                    T::class_name()
-                )
+        let export_info = Self::default_export_info();
+        match export_info.hint {
+            PropertyHint::RESOURCE_TYPE | PropertyHint::NODE_TYPE => {
+                export_info.to_element_type_string(VariantType::Object)
             }
             _ => format!("{}:", VariantType::Object as i32),
         }
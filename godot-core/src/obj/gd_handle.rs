@@ -0,0 +1,168 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::{ConvertError, FromFfiError};
+use crate::builtin::GString;
+use crate::engine::Object;
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// Thread-safe handle to a Godot object, which can be sent to other threads and re-acquired as [`Gd<T>`].
+///
+/// `Gd<T>` is neither `Send` nor `Sync`, as it gives direct access to the object. `GdHandle<T>` only stores the object's
+/// [`InstanceId`]; to access the object, the handle must be turned back into a `Gd<T>` with [`try_get()`][Self::try_get], which checks
+/// that the object is still alive, has the expected class and may be accessed on the current thread.
+///
+/// The handle does not keep the object alive. For ref-counted objects, make sure that a `Gd<T>` is kept somewhere else while the
+/// handle is in use, otherwise the object may be destroyed in the meantime.
+///
+/// # Thread safety contract
+/// Godot only synchronizes a part of its API. When re-acquiring a `Gd<T>` on a thread other than the main thread:
+/// - **Nodes** are refused. The scene tree is not thread-safe, and nodes must only be accessed on the main thread. To modify them from
+///   another thread, use [`run_on_main()`][Self::run_on_main].
+/// - **Resources** and other objects outside the scene tree (e.g. `Image`, `Mesh`, user classes inheriting `RefCounted`) may be used,
+///   as long as a single object is not modified by multiple threads at the same time. Godot does not synchronize such accesses.
+/// - **Servers** such as `RenderingServer` and `PhysicsServer3D` are thread-safe, depending on the project's thread model settings.
+///
+/// The `#[func]`s and `bind()`/`bind_mut()` of user classes are guarded by the `experimental-threads` storage, so concurrent
+/// borrows panic instead of causing undefined behavior.
+///
+/// See also Godot's documentation on [thread-safe APIs](https://docs.godotengine.org/en/stable/tutorials/performance/thread_safe_apis.html).
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::obj::GdHandle;
+/// fn load_in_background(resource: Gd<Resource>, node: Gd<Node>) {
+///     let resource = GdHandle::new(&resource);
+///     let node = GdHandle::new(&node);
+///
+///     std::thread::spawn(move || {
+///         // Resources may be accessed on this thread.
+///         let path = resource.get().get_path().to_string();
+///
+///         // Nodes must be accessed on the main thread.
+///         node.run_on_main(move |mut node| node.set_name(path.as_str().into()));
+///     });
+/// }
+/// ```
+pub struct GdHandle<T: GodotClass> {
+    instance_id: InstanceId,
+
+    // fn() -> T: handle is Send + Sync regardless of T, as it never stores a T.
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: GodotClass> GdHandle<T> {
+    /// Creates a handle to the object behind `gd`.
+    ///
+    /// # Panics
+    /// If the object is dead.
+    pub fn new(gd: &Gd<T>) -> Self {
+        Self {
+            instance_id: gd.instance_id(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the instance ID of the referenced object.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+
+    /// Re-acquires the object as `Gd<T>`, if it may be accessed on the current thread.
+    ///
+    /// Returns an error if:
+    /// - the object has been destroyed,
+    /// - the object's instance ID has been reused by an object not compatible with `T`,
+    /// - the current thread is not the main thread, and the object is a `Node` (see [thread safety contract](#thread-safety-contract)).
+    pub fn try_get(&self) -> Result<Gd<T>, ConvertError> {
+        let object = Gd::<Object>::try_from_instance_id(self.instance_id)?;
+
+        if !sys::is_main_thread() && object.is_class(GString::from("Node")) {
+            return Err(ConvertError::new(format!(
+                "object {} of class '{}' is a Node, which can only be accessed on the main thread",
+                self.instance_id,
+                object.get_class()
+            )));
+        }
+
+        object
+            .owned_cast::<T>()
+            .map_err(|obj| FromFfiError::WrongObjectType.into_error(obj))
+    }
+
+    /// ⚠️ Re-acquires the object as `Gd<T>`.
+    ///
+    /// # Panics
+    /// In the cases where [`try_get()`][Self::try_get] returns an error.
+    pub fn get(&self) -> Gd<T> {
+        self.try_get().unwrap_or_else(|err| {
+            panic!(
+                "failed to re-acquire Gd<{}> from handle: {err}",
+                T::class_name()
+            )
+        })
+    }
+
+    /// Runs `f` on the main thread, with the re-acquired object.
+    ///
+    /// Can be called from any thread; `f` is queued with [`task::run_on_main()`](crate::task::run_on_main) and runs during the next frame.
+    /// If the object is destroyed before that, `f` is dropped without being called.
+    #[cfg(since_api = "4.2")]
+    pub fn run_on_main<F>(&self, f: F)
+    where
+        F: FnOnce(Gd<T>) + Send + 'static,
+    {
+        let handle = *self;
+        crate::task::run_on_main(move || {
+            if let Ok(gd) = Gd::try_from_instance_id(handle.instance_id) {
+                f(gd);
+            }
+        });
+    }
+}
+
+impl<T: GodotClass> From<&Gd<T>> for GdHandle<T> {
+    fn from(gd: &Gd<T>) -> Self {
+        Self::new(gd)
+    }
+}
+
+// Manual impls: derives would require `T: Clone` etc.
+impl<T: GodotClass> Clone for GdHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GodotClass> Copy for GdHandle<T> {}
+
+impl<T: GodotClass> PartialEq for GdHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: GodotClass> Eq for GdHandle<T> {}
+
+impl<T: GodotClass> Hash for GdHandle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instance_id.hash(state);
+    }
+}
+
+impl<T: GodotClass> Debug for GdHandle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "GdHandle<{}>({})", T::class_name(), self.instance_id)
+    }
+}
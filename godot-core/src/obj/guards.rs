@@ -5,7 +5,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot_cell::{InaccessibleGuard, MutGuard, RefGuard};
+use godot_cell::{BorrowStateErr, InaccessibleGuard, MutGuard, RefGuard};
+
+pub use godot_cell::{BorrowKind, BorrowSite};
 use godot_ffi::out;
 
 use std::fmt::Debug;
@@ -13,7 +15,7 @@ use std::ops::{Deref, DerefMut};
 
 use crate::engine::ScriptInstance;
 
-use super::{Gd, GodotClass};
+use super::{AsDyn, Gd, GodotClass};
 
 /// Immutably/shared bound reference guard for a [`Gd`][crate::obj::Gd] smart pointer.
 ///
@@ -83,6 +85,173 @@ impl<T: GodotClass> Drop for GdMut<'_, T> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Error returned by [`Gd::try_bind()`][crate::obj::Gd::try_bind] and [`Gd::try_bind_mut()`][crate::obj::Gd::try_bind_mut].
+///
+/// Describes the existing borrows that prevented the instance from being bound. In Debug mode, this includes where they were taken; in
+/// Release mode, `sites` is always empty.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BindError {
+    /// The instance is bound exclusively, by a [`GdMut`] guard or a `&mut self` method.
+    ExclusivelyBound {
+        /// Where the existing borrows were taken, oldest first.
+        sites: Vec<BorrowSite>,
+    },
+
+    /// The instance is bound by shared borrows ([`GdRef`] guards or `&self` methods), which prevent binding it exclusively.
+    SharedBound {
+        /// Number of shared borrows.
+        count: usize,
+
+        /// Where the existing borrows were taken, oldest first.
+        sites: Vec<BorrowSite>,
+    },
+
+    /// The borrow tracking itself failed. Should not happen in practice.
+    Internal(String),
+}
+
+impl BindError {
+    /// Where the borrows that prevented binding were taken, oldest first.
+    ///
+    /// Only recorded in Debug mode; empty in Release mode.
+    pub fn borrow_sites(&self) -> &[BorrowSite] {
+        match self {
+            Self::ExclusivelyBound { sites } | Self::SharedBound { sites, .. } => sites,
+            Self::Internal(_) => &[],
+        }
+    }
+
+    pub(crate) fn from_cell_error(err: BorrowStateErr) -> Self {
+        match err {
+            BorrowStateErr::Blocked {
+                has_accessible_mut: true,
+                sites,
+                ..
+            } => Self::ExclusivelyBound { sites },
+            BorrowStateErr::Blocked {
+                shared_count,
+                sites,
+                ..
+            } => Self::SharedBound {
+                count: shared_count,
+                sites,
+            },
+            other => Self::Internal(other.to_string()),
+        }
+    }
+
+    /// Lists the borrow sites, to be appended to the panic message of `bind()`/`bind_mut()`. Empty if no sites were recorded.
+    pub(crate) fn format_borrow_sites(&self) -> String {
+        let sites = self.borrow_sites();
+        if sites.is_empty() {
+            return String::new();
+        }
+
+        let mut result = String::from("\n  Existing borrows (oldest first):");
+        for site in sites {
+            result.push_str(&format!("\n    - {site}"));
+        }
+
+        result
+    }
+}
+
+impl std::fmt::Display for BindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ExclusivelyBound { .. } => write!(
+                f,
+                "instance is already bound exclusively (by a `GdMut` guard or a `&mut self` method)"
+            ),
+            Self::SharedBound { count, .. } => write!(
+                f,
+                "instance is already bound by {count} shared borrow(s) (`GdRef` guards or `&self` methods)"
+            ),
+            Self::Internal(msg) => write!(f, "borrow tracking failed: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for BindError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Shared bound reference guard for a [`DynGd`][crate::obj::DynGd] smart pointer, dereferencing to the trait object `D`.
+///
+/// See [`DynGd::dyn_bind`][crate::obj::DynGd::dyn_bind] for usage.
+pub struct DynGdRef<'a, D: ?Sized> {
+    /// Never accessed, but keeps the borrow alive.
+    _guard: Box<dyn ErasedGuard<'a>>,
+
+    /// Points into the user instance, which is pinned in its storage and thus does not move together with the guard.
+    cached_ptr: *const D,
+}
+
+impl<'a, D: ?Sized> DynGdRef<'a, D> {
+    pub(crate) fn from_guard<T: AsDyn<D>>(guard: GdRef<'a, T>) -> Self {
+        let cached_ptr: *const D = <T as AsDyn<D>>::dyn_upcast(&guard);
+
+        Self {
+            _guard: Box::new(guard),
+            cached_ptr,
+        }
+    }
+}
+
+impl<D: ?Sized> Deref for DynGdRef<'_, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        // SAFETY: the guard keeps the shared borrow of the instance alive as long as self.
+        unsafe { &*self.cached_ptr }
+    }
+}
+
+/// Exclusive bound reference guard for a [`DynGd`][crate::obj::DynGd] smart pointer, dereferencing to the trait object `D`.
+///
+/// See [`DynGd::dyn_bind_mut`][crate::obj::DynGd::dyn_bind_mut] for usage.
+pub struct DynGdMut<'a, D: ?Sized> {
+    /// Never accessed, but keeps the borrow alive.
+    _guard: Box<dyn ErasedGuard<'a>>,
+
+    /// Points into the user instance, which is pinned in its storage and thus does not move together with the guard.
+    cached_ptr: *mut D,
+}
+
+impl<'a, D: ?Sized> DynGdMut<'a, D> {
+    pub(crate) fn from_guard<T: AsDyn<D>>(mut guard: GdMut<'a, T>) -> Self {
+        let cached_ptr: *mut D = <T as AsDyn<D>>::dyn_upcast_mut(&mut guard);
+
+        Self {
+            _guard: Box::new(guard),
+            cached_ptr,
+        }
+    }
+}
+
+impl<D: ?Sized> Deref for DynGdMut<'_, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        // SAFETY: the guard keeps the exclusive borrow of the instance alive as long as self.
+        unsafe { &*self.cached_ptr }
+    }
+}
+
+impl<D: ?Sized> DerefMut for DynGdMut<'_, D> {
+    fn deref_mut(&mut self) -> &mut D {
+        // SAFETY: the guard keeps the exclusive borrow of the instance alive as long as self, and &mut self prevents aliasing.
+        unsafe { &mut *self.cached_ptr }
+    }
+}
+
+/// Type-erased `GdRef` or `GdMut`, so that dyn guards don't depend on the concrete class.
+trait ErasedGuard<'a>: 'a {}
+
+impl<'a, T: 'a> ErasedGuard<'a> for T {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 macro_rules! make_base_ref {
     ($ident:ident, $bound:ident, $doc_type:ident, $doc_path:path, $object_name:literal) => {
         /// Shared reference guard for a [`Base`](crate::obj::Base) pointer.
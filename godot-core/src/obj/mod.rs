@@ -12,7 +12,10 @@
 //! * [`Gd`], a smart pointer that manages instances of Godot classes.
 
 mod base;
+mod dyn_gd;
 mod gd;
+#[cfg(feature = "experimental-threads")]
+mod gd_handle;
 mod guards;
 mod instance_id;
 mod onready;
@@ -22,7 +25,10 @@ mod traits;
 pub(crate) mod rtti;
 
 pub use base::*;
+pub use dyn_gd::*;
 pub use gd::*;
+#[cfg(feature = "experimental-threads")]
+pub use gd_handle::*;
 pub use guards::*;
 pub use instance_id::*;
 pub use onready::*;
@@ -17,7 +17,7 @@ use crate::builtin::meta::{
 use crate::builtin::Variant;
 use crate::obj::bounds::DynMemory as _;
 use crate::obj::rtti::ObjectRtti;
-use crate::obj::{bounds, Bounds, GdDerefTarget, GdMut, GdRef, GodotClass, InstanceId};
+use crate::obj::{bounds, BindError, Bounds, GdDerefTarget, GdMut, GdRef, GodotClass, InstanceId};
 use crate::storage::{InstanceStorage, Storage};
 use crate::{engine, out};
 
@@ -365,6 +365,7 @@ where
     ///
     /// See [`crate::obj::Gd::bind()`] for a more in depth explanation.
     // Note: possible names: write/read, hold/hold_mut, r/w, r/rw, ...
+    #[track_caller]
     pub(crate) fn bind(&self) -> GdRef<T> {
         self.check_rtti("bind");
         GdRef::from_guard(self.storage().unwrap().get())
@@ -373,11 +374,26 @@ where
     /// Hands out a guard for an exclusive borrow, through which the user instance can be read and written.
     ///
     /// See [`crate::obj::Gd::bind_mut()`] for a more in depth explanation.
+    #[track_caller]
     pub(crate) fn bind_mut(&mut self) -> GdMut<T> {
         self.check_rtti("bind_mut");
         GdMut::from_guard(self.storage().unwrap().get_mut())
     }
 
+    /// Like [`bind()`][Self::bind], but returns an error instead of panicking if the instance is already bound.
+    #[track_caller]
+    pub(crate) fn try_bind(&self) -> Result<GdRef<T>, BindError> {
+        self.check_rtti("try_bind");
+        self.storage().unwrap().try_get().map(GdRef::from_guard)
+    }
+
+    /// Like [`bind_mut()`][Self::bind_mut], but returns an error instead of panicking if the instance is already bound.
+    #[track_caller]
+    pub(crate) fn try_bind_mut(&mut self) -> Result<GdMut<T>, BindError> {
+        self.check_rtti("try_bind_mut");
+        self.storage().unwrap().try_get_mut().map(GdMut::from_guard)
+    }
+
     /// Storage object associated with the extension instance.
     ///
     /// Returns `None` if self is null.
@@ -7,7 +7,7 @@
 
 use crate::builder::ClassBuilder;
 use crate::builtin::meta::ClassName;
-use crate::builtin::GString;
+use crate::builtin::{GString, SignalObject, StringName, Variant};
 use crate::init::InitLevel;
 use crate::obj::{bounds, Base, BaseMut, BaseRef, Bounds, Gd};
 use crate::storage::Storage;
@@ -148,6 +148,21 @@ pub trait UserClass: Bounds<Declarer = bounds::DeclUser> {
     fn __default_virtual_call(_method_name: &str) -> sys::GDExtensionClassCallVirtual {
         None
     }
+
+    /// Revert value of a property, as given by `#[init(default = ...)]`.
+    #[doc(hidden)]
+    fn __default_property_revert(_property: &StringName) -> Option<Variant> {
+        None
+    }
+}
+
+/// Implemented for user classes that can be viewed as the trait object `Trait`, e.g. `dyn Damageable`.
+///
+/// Don't implement this trait manually; use [`#[godot_dyn]`](../register/attr.godot_dyn.html) on the trait impl instead, which also
+/// registers the class for runtime lookup in [`DynGd`][crate::obj::DynGd].
+pub trait AsDyn<Trait: ?Sized>: GodotClass + Bounds<Declarer = bounds::DeclUser> {
+    fn dyn_upcast(&self) -> &Trait;
+    fn dyn_upcast_mut(&mut self) -> &mut Trait;
 }
 
 /// Auto-implemented for all engine-provided enums.
@@ -374,6 +389,33 @@ pub trait WithBaseField: GodotClass + Bounds<Declarer = bounds::DeclUser> {
     }
 }
 
+/// Trait that's implemented for user-defined classes that declare at least one `#[signal]`.
+///
+/// Provides a collection of type-safe signals, which is generated by `#[godot_api]`. Access it from outside the class
+/// through [`Gd::signals()`], or from inside through [`WithUserSignals::signals()`].
+pub trait WithSignals: GodotClass {
+    /// Generated type with one accessor method per signal, e.g. `hit()` for `#[signal] fn hit(damage: i64)`.
+    type SignalCollection<'c>;
+
+    #[doc(hidden)]
+    fn __signals_from_object(object: SignalObject<'_, Self>) -> Self::SignalCollection<'_>;
+}
+
+/// Access to the type-safe signals of a user class, from within `self`.
+///
+/// Requires a `Base<T>` field, as emitting signals needs the base object.
+pub trait WithUserSignals: WithSignals + WithBaseField {
+    /// Returns the collection of type-safe signals declared by this class.
+    ///
+    /// Signals emitted through this collection can be handled re-entrantly: connected functions may call back into `self`,
+    /// similar to [`base_mut()`](WithBaseField::base_mut).
+    fn signals(&mut self) -> Self::SignalCollection<'_> {
+        Self::__signals_from_object(SignalObject::from_instance(self))
+    }
+}
+
+impl<T> WithUserSignals for T where T: WithSignals + WithBaseField {}
+
 /// Extension trait for all reference-counted classes.
 pub trait NewGd: GodotClass {
     /// Return a new, ref-counted `Gd` containing a default-constructed instance.
@@ -417,8 +459,10 @@ where
 /// Capability traits, providing dedicated functionalities for Godot classes
 pub mod cap {
     use super::*;
+    use crate::builtin::meta::PropertyInfo;
     use crate::builtin::{StringName, Variant};
     use crate::obj::{Base, Bounds, Gd};
+    use std::any::Any;
 
     /// Trait for all classes that are default-constructible from the Godot engine.
     ///
@@ -504,12 +548,32 @@ pub mod cap {
         fn __godot_set_property(&mut self, property: StringName, value: Variant) -> bool;
     }
 
+    #[doc(hidden)]
+    pub trait GodotGetPropertyList: GodotClass {
+        #[doc(hidden)]
+        fn __godot_get_property_list(&self) -> Vec<PropertyInfo>;
+    }
+
+    #[doc(hidden)]
+    pub trait GodotValidateProperty: GodotClass {
+        #[doc(hidden)]
+        fn __godot_validate_property(&self, property: &mut PropertyInfo);
+    }
+
+    #[doc(hidden)]
+    pub trait GodotPropertyGetRevert: GodotClass {
+        #[doc(hidden)]
+        fn __godot_property_get_revert(&self, property: StringName) -> Option<Variant>;
+    }
+
     /// Auto-implemented for `#[godot_api] impl MyClass` blocks
     pub trait ImplementsGodotApi: GodotClass {
         #[doc(hidden)]
         fn __register_methods();
         #[doc(hidden)]
         fn __register_constants();
+        #[doc(hidden)]
+        fn __register_rpcs(_: &mut dyn Any) {}
     }
 
     pub trait ImplementsGodotExports: GodotClass {
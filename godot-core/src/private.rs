@@ -13,10 +13,11 @@ pub use sys::out;
 
 #[cfg(feature = "trace")]
 pub use crate::builtin::meta::trace;
-use crate::builtin::meta::{CallContext, CallError};
+use crate::builtin::meta::{CallContext, CallError, ToGodot};
+use crate::builtin::Variant;
 pub use crate::gen::classes::class_macros;
 pub use crate::obj::rtti::ObjectRtti;
-pub use crate::registry::{callbacks, ClassPlugin, ErasedRegisterFn, PluginItem};
+pub use crate::registry::{callbacks, ClassPlugin, DynTraitImpl, ErasedRegisterFn, PluginItem};
 pub use crate::storage::{as_storage, Storage};
 use crate::{log, sys};
 
@@ -252,6 +253,31 @@ pub fn handle_varcall_panic<F, R>(
     //sys::interface_fn!(variant_new_nil)(sys::AsUninit::as_uninit(ret));
 }
 
+/// Converts the return value of a `#[func]` returning `Result<T, E>`.
+///
+/// `Ok` values are converted to variants. An `Err` is reported as a script error, pointing to the method's source location, and
+/// `fallback` is returned to the caller instead.
+pub fn func_result_to_variant<T, E>(
+    result: Result<T, E>,
+    fallback: impl FnOnce() -> Variant,
+    function_name: &str,
+    file: &str,
+    line: u32,
+) -> Variant
+where
+    T: ToGodot,
+    E: std::fmt::Display,
+{
+    let err = match result {
+        Ok(value) => return value.to_variant(),
+        Err(err) => err,
+    };
+
+    crate::inner_godot_msg!(print_script_error @ function_name, file, line; "{function_name}: {err}");
+
+    fallback()
+}
+
 fn handle_panic_with_print<E, F, R, S>(error_context: E, code: F, print: bool) -> Result<R, String>
 where
     E: FnOnce() -> S,
@@ -8,8 +8,9 @@
 //! Registration support for property types.
 
 use crate::builtin::meta::{FromGodot, GodotConvert, ToGodot};
-use crate::builtin::GString;
+use crate::builtin::{GString, VariantType};
 use crate::engine::global::PropertyHint;
+use crate::obj::EngineEnum;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Trait definitions
@@ -48,10 +49,20 @@ pub trait Var: GodotConvert {
 pub trait Export: Var {
     /// The export info to use for an exported field of this type, if no other export info is specified.
     fn default_export_info() -> PropertyHintInfo;
+
+    /// Variant type of the elements, if this is a typed array or packed array.
+    #[doc(hidden)]
+    fn element_variant_type() -> Option<VariantType> {
+        None
+    }
 }
 
 /// Marks types that are registered via "type string hint" in Godot.
 ///
+/// This is used for the elements of exported arrays. Besides the type, the type string can carry a hint for each element, e.g. a
+/// resource class or enum values, which lets the editor show a typed element picker. Element hints can be created with
+/// [`PropertyHintInfo::to_element_type_string()`]. Nested arrays prefix the type string of their elements, e.g. `"28:2/2:Low,High"`.
+///
 /// See [`PropertyHint::TYPE_STRING`] and [upstream docs].
 ///
 /// [upstream docs]: https://docs.godotengine.org/en/stable/classes/class_%40globalscope.html#enum-globalscope-propertyhint
@@ -64,6 +75,15 @@ pub trait TypeStringHint {
     fn type_string() -> String;
 }
 
+/// Arrays whose elements can be given an export hint, with `#[export(element = (...))]`.
+///
+/// Implemented for typed [`Array<T>`][crate::builtin::Array] and packed arrays. Untyped arrays don't know their element type, so the
+/// hint cannot apply to them.
+pub trait ElementExport: Export {
+    /// Type of the elements.
+    type Element: GodotConvert;
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Blanket impls for Option<T>
 
@@ -88,7 +108,11 @@ where
                 if let Some(current_value) = self {
                     current_value.set_property(value)
                 } else {
-                    *self = Some(FromGodot::from_godot(value))
+                    // Conversion can fail for types that are stricter than their `Via`, e.g. `DynGd`; keep `None` then.
+                    match T::try_from_godot(value) {
+                        Ok(value) => *self = Some(value),
+                        Err(err) => crate::godot_error!("cannot assign property value: {err}"),
+                    }
                 }
             }
             None => *self = None,
@@ -104,6 +128,10 @@ where
     fn default_export_info() -> PropertyHintInfo {
         T::default_export_info()
     }
+
+    fn element_variant_type() -> Option<VariantType> {
+        T::element_variant_type()
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -127,14 +155,30 @@ impl PropertyHintInfo {
             hint_string: type_name.into(),
         }
     }
+
+    /// Type string for an element of type `element_type` with this hint, as used by [`TypeStringHint`].
+    ///
+    /// For example, an integer enum becomes `"2/2:Low,High"`. Without hint, only the type and hint string are included, e.g. `"2:int"`.
+    pub fn to_element_type_string(&self, element_type: VariantType) -> String {
+        if self.hint == PropertyHint::NONE {
+            format!("{}:{}", element_type as i32, self.hint_string)
+        } else {
+            format!(
+                "{}/{}:{}",
+                element_type as i32,
+                self.hint.ord(),
+                self.hint_string
+            )
+        }
+    }
 }
 
 /// Functions used to translate user-provided arguments into export hints.
 pub mod export_info_functions {
-    use crate::builtin::GString;
+    use crate::builtin::{GString, VariantType};
     use crate::engine::global::PropertyHint;
 
-    use super::PropertyHintInfo;
+    use super::{ElementExport, PropertyHintInfo};
 
     /// Turn a list of variables into a comma separated string containing only the identifiers corresponding
     /// to a true boolean variable.
@@ -293,6 +337,84 @@ pub mod export_info_functions {
         }
     }
 
+    /// `@export_node_path`: restricts a `NodePath` property to nodes of the given classes. An empty list allows any node.
+    pub fn export_node_path<S: AsRef<str>>(class_names: &[S]) -> PropertyHintInfo {
+        let hint_string = class_names
+            .iter()
+            .map(|name| name.as_ref())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        PropertyHintInfo {
+            hint: PropertyHint::NODE_PATH_VALID_TYPES,
+            hint_string: hint_string.into(),
+        }
+    }
+
+    /// `@export_custom`: uses the given hint and hint string verbatim.
+    pub fn export_custom<S: AsRef<str>>(hint: PropertyHint, hint_string: S) -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint,
+            hint_string: hint_string.as_ref().into(),
+        }
+    }
+
+    /// Applies `element_hint` to each element of an array property, which holds elements of type `element_type`.
+    ///
+    /// Godot represents this as a [`PropertyHint::TYPE_STRING`] hint with hint string `"{element_type}/{hint}:{hint_string}"`.
+    pub fn export_array_element(
+        element_type: VariantType,
+        element_hint: PropertyHintInfo,
+    ) -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: PropertyHint::TYPE_STRING,
+            hint_string: element_hint.to_element_type_string(element_type).into(),
+        }
+    }
+
+    /// Applies `element_hint` to each element of the array or packed array `A`.
+    ///
+    /// Used by `#[export(element = (...))]`, e.g. for a range of values in an `Array<i32>`.
+    pub fn export_element<A: ElementExport>(element_hint: PropertyHintInfo) -> PropertyHintInfo {
+        use crate::builtin::meta::{GodotConvert, GodotType};
+        use godot_ffi::GodotFfi as _;
+
+        let element_type = <<A::Element as GodotConvert>::Via as GodotType>::Ffi::variant_type();
+
+        export_array_element(element_type, element_hint)
+    }
+
+    /// Returns `element_hint` for a property of type `element_type`, or applies it to the elements if the property is an array of
+    /// `element_type`.
+    ///
+    /// Used for annotations like `@export_multiline`, which GDScript accepts on both `String` and `Array[String]`. For any other
+    /// property type, including arrays of other elements, the hint does not apply and the default export info of `P` is returned.
+    pub fn export_element_hint<P: Export>(
+        element_type: VariantType,
+        element_hint: PropertyHintInfo,
+    ) -> PropertyHintInfo {
+        use crate::builtin::meta::GodotType;
+        use godot_ffi::GodotFfi as _;
+
+        let property_type = <P::Via as GodotType>::Ffi::variant_type();
+
+        if property_type == element_type {
+            element_hint
+        } else if P::element_variant_type() == Some(element_type) {
+            export_array_element(element_type, element_hint)
+        } else {
+            P::default_export_info()
+        }
+    }
+
+    #[cfg(since_api = "4.1")]
+    pub fn export_flags_avoidance() -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: PropertyHint::LAYERS_AVOIDANCE,
+            hint_string: GString::new(),
+        }
+    }
+
     macro_rules! default_export_funcs {
         (
             $( $function_name:ident => $property_hint:ident, )*
@@ -321,6 +443,7 @@ pub mod export_info_functions {
         export_dir => DIR,
         export_global_dir => GLOBAL_DIR,
         export_multiline => MULTILINE_TEXT,
+        export_expression => EXPRESSION,
         export_color_no_alpha => COLOR_NO_ALPHA,
     );
 }
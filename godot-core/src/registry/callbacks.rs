@@ -11,28 +11,45 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::builder::ClassBuilder;
+use crate::builtin::meta::PropertyInfo;
 use crate::builtin::{StringName, Variant};
-use crate::obj::{cap, Base, GodotClass, UserClass};
+use crate::obj::{cap, Base, Gd, GodotClass, UserClass};
+use crate::registry::ErasedRegisterFn;
 use crate::storage::{as_storage, InstanceStorage, Storage, StorageRefCounted};
 use godot_ffi as sys;
 use std::any::Any;
 use sys::interface_fn;
 
 pub unsafe extern "C" fn create<T: cap::GodotDefault>(
-    _class_userdata: *mut std::ffi::c_void,
+    class_userdata: *mut std::ffi::c_void,
 ) -> sys::GDExtensionObjectPtr {
-    create_custom(T::__godot_user_init)
+    let register_rpcs_fn = rpc_registration_from_userdata(class_userdata);
+    create_custom_with_rpcs(T::__godot_user_init, register_rpcs_fn)
 }
 
 #[cfg(since_api = "4.2")]
 pub unsafe extern "C" fn recreate<T: cap::GodotDefault>(
-    _class_userdata: *mut std::ffi::c_void,
+    class_userdata: *mut std::ffi::c_void,
     object: sys::GDExtensionObjectPtr,
 ) -> sys::GDExtensionClassInstancePtr {
-    create_rust_part_for_existing_godot_part(T::__godot_user_init, object)
+    let register_rpcs_fn = rpc_registration_from_userdata(class_userdata);
+    create_rust_part_for_existing_godot_part(T::__godot_user_init, object, register_rpcs_fn)
 }
 
 pub(crate) fn create_custom<T, F>(make_user_instance: F) -> sys::GDExtensionObjectPtr
+where
+    T: GodotClass,
+    F: FnOnce(Base<T::Base>) -> T,
+{
+    // Objects constructed from Rust don't go through Godot's create callback, so they don't receive the class userdata.
+    let register_rpcs_fn = crate::registry::find_rpc_registration(T::class_name());
+    create_custom_with_rpcs(make_user_instance, register_rpcs_fn)
+}
+
+fn create_custom_with_rpcs<T, F>(
+    make_user_instance: F,
+    register_rpcs_fn: Option<ErasedRegisterFn>,
+) -> sys::GDExtensionObjectPtr
 where
     T: GodotClass,
     F: FnOnce(Base<T::Base>) -> T,
@@ -41,7 +58,7 @@ where
 
     let base_ptr = unsafe { interface_fn!(classdb_construct_object)(base_class_name.string_sys()) };
 
-    create_rust_part_for_existing_godot_part(make_user_instance, base_ptr);
+    create_rust_part_for_existing_godot_part(make_user_instance, base_ptr, register_rpcs_fn);
 
     // std::mem::forget(base_class_name);
     base_ptr
@@ -55,6 +72,7 @@ where
 fn create_rust_part_for_existing_godot_part<T, F>(
     make_user_instance: F,
     base_ptr: sys::GDExtensionObjectPtr,
+    register_rpcs_fn: Option<ErasedRegisterFn>,
 ) -> sys::GDExtensionClassInstancePtr
 where
     T: GodotClass,
@@ -82,10 +100,31 @@ where
         );
     }
 
+    // Apply #[rpc] configurations, now that the instance is fully bound. Only classes inheriting Node can have RPCs.
+    if let Some(register_rpcs_fn) = register_rpcs_fn {
+        let mut object = unsafe { Gd::<T>::from_obj_sys(base_ptr) };
+        (register_rpcs_fn.raw)(&mut object);
+    }
+
     // std::mem::forget(class_name);
     instance_ptr
 }
 
+/// Restores the function applying `#[rpc]` configurations, which is passed to Godot as class userdata upon class registration.
+///
+/// # Safety
+/// `class_userdata` must be the userdata registered for the class, i.e. either null or an `ErasedRegisterFn::raw` pointer.
+unsafe fn rpc_registration_from_userdata(
+    class_userdata: *mut std::ffi::c_void,
+) -> Option<ErasedRegisterFn> {
+    if class_userdata.is_null() {
+        return None;
+    }
+
+    let raw = std::mem::transmute::<*mut std::ffi::c_void, fn(&mut dyn Any)>(class_userdata);
+    Some(ErasedRegisterFn { raw })
+}
+
 pub unsafe extern "C" fn free<T: GodotClass>(
     _class_user_data: *mut std::ffi::c_void,
     instance: sys::GDExtensionClassInstancePtr,
@@ -191,6 +230,139 @@ pub unsafe extern "C" fn set_property<T: cap::GodotSet>(
     T::__godot_set_property(&mut *instance, property, value) as sys::GDExtensionBool
 }
 
+pub unsafe extern "C" fn get_property_list<T: cap::GodotGetPropertyList>(
+    instance: sys::GDExtensionClassInstancePtr,
+    count: *mut u32,
+) -> *const sys::GDExtensionPropertyInfo {
+    let storage = as_storage::<T>(instance);
+    let instance = storage.get();
+    let property_list = T::__godot_get_property_list(&*instance);
+
+    *count = u32::try_from(property_list.len()).expect("property list too long");
+
+    // Terminated by an entry with null name, so free_property_list() can reconstruct the length on all API versions.
+    let list_sys: Box<[sys::GDExtensionPropertyInfo]> = property_list
+        .into_iter()
+        .map(PropertyInfo::into_owned_property_sys)
+        .chain(std::iter::once(PropertyInfo::empty_sys()))
+        .collect();
+
+    Box::into_raw(list_sys) as *const sys::GDExtensionPropertyInfo
+}
+
+#[cfg(before_api = "4.3")]
+pub unsafe extern "C" fn free_property_list(
+    _instance: sys::GDExtensionClassInstancePtr,
+    list: *const sys::GDExtensionPropertyInfo,
+) {
+    free_property_list_impl(list);
+}
+
+#[cfg(since_api = "4.3")]
+pub unsafe extern "C" fn free_property_list(
+    _instance: sys::GDExtensionClassInstancePtr,
+    list: *const sys::GDExtensionPropertyInfo,
+    _count: u32,
+) {
+    free_property_list_impl(list);
+}
+
+unsafe fn free_property_list_impl(list: *const sys::GDExtensionPropertyInfo) {
+    if list.is_null() {
+        return;
+    }
+
+    let mut len = 0;
+    while !(*list.add(len)).name.is_null() {
+        len += 1;
+    }
+
+    // Includes the terminating entry.
+    let list_ptr =
+        std::ptr::slice_from_raw_parts_mut(list as *mut sys::GDExtensionPropertyInfo, len + 1);
+    let list_sys = Box::from_raw(list_ptr);
+
+    for info in list_sys[..len].iter() {
+        PropertyInfo::free_owned_property_sys(*info);
+    }
+}
+
+pub unsafe extern "C" fn validate_property<T: cap::GodotValidateProperty>(
+    instance: sys::GDExtensionClassInstancePtr,
+    property: *mut sys::GDExtensionPropertyInfo,
+) -> sys::GDExtensionBool {
+    let storage = as_storage::<T>(instance);
+    let instance = storage.get();
+
+    let property = &mut *property;
+    let mut info = PropertyInfo::new_from_sys(property);
+    T::__godot_validate_property(&*instance, &mut info);
+    info.write_into_sys(property);
+
+    true as sys::GDExtensionBool
+}
+
+pub unsafe extern "C" fn property_can_revert<T: cap::GodotPropertyGetRevert + UserClass>(
+    instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+) -> sys::GDExtensionBool {
+    property_revert_value::<T>(instance, name).is_some() as sys::GDExtensionBool
+}
+
+pub unsafe extern "C" fn property_get_revert<T: cap::GodotPropertyGetRevert + UserClass>(
+    instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+    ret: sys::GDExtensionVariantPtr,
+) -> sys::GDExtensionBool {
+    match property_revert_value::<T>(instance, name) {
+        Some(value) => {
+            value.move_into_var_ptr(ret);
+            true as sys::GDExtensionBool
+        }
+        None => false as sys::GDExtensionBool,
+    }
+}
+
+/// User-defined revert value, falling back to the `#[init(default = ...)]` one.
+unsafe fn property_revert_value<T: cap::GodotPropertyGetRevert + UserClass>(
+    instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+) -> Option<Variant> {
+    let storage = as_storage::<T>(instance);
+    let instance = storage.get();
+
+    // This string is not ours, so we cannot call the destructor on it.
+    let property = StringName::borrow_string_sys(name);
+
+    T::__godot_property_get_revert(&*instance, property.clone())
+        .or_else(|| T::__default_property_revert(property))
+}
+
+pub unsafe extern "C" fn default_property_can_revert<T: UserClass>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+) -> sys::GDExtensionBool {
+    let property = StringName::borrow_string_sys(name);
+
+    T::__default_property_revert(property).is_some() as sys::GDExtensionBool
+}
+
+pub unsafe extern "C" fn default_property_get_revert<T: UserClass>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+    ret: sys::GDExtensionVariantPtr,
+) -> sys::GDExtensionBool {
+    let property = StringName::borrow_string_sys(name);
+
+    match T::__default_property_revert(property) {
+        Some(value) => {
+            value.move_into_var_ptr(ret);
+            true as sys::GDExtensionBool
+        }
+        None => false as sys::GDExtensionBool,
+    }
+}
+
 pub unsafe extern "C" fn reference<T: GodotClass>(instance: sys::GDExtensionClassInstancePtr) {
     let storage = as_storage::<T>(instance);
     storage.on_inc_ref();
@@ -238,3 +410,7 @@ pub fn register_user_methods_constants<T: cap::ImplementsGodotApi>(_class_builde
     T::__register_methods();
     T::__register_constants();
 }
+
+pub fn register_user_rpcs<T: cap::ImplementsGodotApi>(object: &mut dyn Any) {
+    T::__register_rpcs(object);
+}
@@ -7,15 +7,18 @@
 
 use crate::builtin::meta::ClassName;
 use crate::init::InitLevel;
-use crate::obj::{cap, GodotClass};
-use crate::{godot_error, out};
+use crate::obj::{cap, AsDyn, Gd, GodotClass};
+use crate::{engine, godot_error, out};
 use godot_ffi as sys;
-use std::any::Any;
+use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::{fmt, ptr};
 use sys::{interface_fn, Global, GlobalGuard, GlobalLockError};
 
 pub mod callbacks;
+mod rpc_config;
+
+pub use rpc_config::RpcConfig;
 
 // Needed for class unregistering. The variable is populated during class registering. There is no actual concurrency here, because Godot
 // calls register/unregister in the main thread. Mutex is just casual way to ensure safety in this non-performance-critical path.
@@ -23,6 +26,14 @@ pub mod callbacks;
 // side and analysis required to adopt these changes.
 static LOADED_CLASSES: Global<HashMap<InitLevel, Vec<LoadedClass>>> = Global::default();
 
+// Classes declaring `#[rpc]` methods, mapped to the function that configures those RPCs on a new instance. Populated during class
+// registering and queried by `Gd::from_init_fn()`; instances created by Godot receive the function as class userdata instead.
+static RPC_REGISTRATIONS: Global<HashMap<ClassName, ErasedRegisterFn>> = Global::default();
+
+// Trait objects implemented by classes via `#[godot_dyn]`, keyed by class and `TypeId` of the `dyn Trait` type. Populated during class
+// registering and queried when a `DynGd` is created from an object whose concrete class is only known at runtime.
+static DYN_TRAIT_IMPLS: Global<HashMap<(ClassName, TypeId), DynTraitImpl>> = Global::default();
+
 // TODO(bromeon): some information coming from the proc-macro API is deferred through PluginItem, while others is directly
 // translated to code. Consider moving more code to the PluginItem, which allows for more dynamic registration and will
 // be easier for a future builder API.
@@ -53,6 +64,57 @@ impl fmt::Debug for ErasedRegisterFn {
     }
 }
 
+/// Type-erased implementation of a trait for a class, registered by `#[godot_dyn]`.
+#[derive(Copy, Clone)]
+pub struct DynTraitImpl {
+    dyn_trait_type_id: TypeId,
+    dyn_trait_name: &'static str,
+
+    /// Converts a `Gd<Object>` of the implementing class to `Box<dyn ErasedGd<D>>`, which is returned inside `Box<dyn Any>`.
+    erased_dynify_fn: fn(Gd<engine::Object>) -> Box<dyn Any>,
+}
+
+impl DynTraitImpl {
+    pub fn new<T, D>() -> Self
+    where
+        T: AsDyn<D>,
+        D: ?Sized + 'static,
+    {
+        fn dynify<T, D>(object: Gd<engine::Object>) -> Box<dyn Any>
+        where
+            T: AsDyn<D>,
+            D: ?Sized + 'static,
+        {
+            let gd = object.owned_cast::<T>().unwrap_or_else(|obj| {
+                panic!(
+                    "DynTraitImpl for class {} used with {obj:?}",
+                    T::class_name()
+                )
+            });
+
+            let erased: Box<dyn crate::obj::ErasedGd<D>> = Box::new(gd);
+            Box::new(erased)
+        }
+
+        Self {
+            dyn_trait_type_id: TypeId::of::<D>(),
+            dyn_trait_name: std::any::type_name::<D>(),
+            erased_dynify_fn: dynify::<T, D>,
+        }
+    }
+
+    /// Returns `Box<dyn ErasedGd<D>>` inside `Box<dyn Any>`; the object must be of the implementing class.
+    pub(crate) fn dynify(&self, object: Gd<engine::Object>) -> Box<dyn Any> {
+        (self.erased_dynify_fn)(object)
+    }
+}
+
+impl fmt::Debug for DynTraitImpl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DynTraitImpl({})", self.dyn_trait_name)
+    }
+}
+
 /// Represents the data part of a [`ClassPlugin`] instance.
 ///
 /// Each enumerator represents a different item in Rust code, which is processed by an independent proc macro (for example,
@@ -94,6 +156,21 @@ pub enum PluginItem {
             ) -> sys::GDExtensionClassCallVirtual,
         >,
 
+        /// Reverts properties to their `#[init(default = ...)]` values. Set if at least one `#[var]` or `#[export]` field has one.
+        default_property_can_revert_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+            ) -> sys::GDExtensionBool,
+        >,
+        default_property_get_revert_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+                r_ret: sys::GDExtensionVariantPtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
         /// Whether `#[class(tool)]` was used.
         is_tool: bool,
 
@@ -113,6 +190,11 @@ pub enum PluginItem {
         ///
         /// Always present since that's the entire point of this `impl` block.
         register_methods_constants_fn: ErasedRegisterFn,
+
+        /// Callback to library-generated function which applies `#[rpc]` configurations to a new instance.
+        ///
+        /// Present if at least one `#[func]` in the `impl` block has an `#[rpc]` attribute.
+        register_rpcs_fn: Option<ErasedRegisterFn>,
     },
 
     /// Collected from `#[godot_api] impl I... for MyClass`.
@@ -176,12 +258,43 @@ pub enum PluginItem {
             ) -> sys::GDExtensionBool,
         >,
 
+        /// User-defined `property_get_revert` function, wired up to both `property_can_revert` and `property_get_revert`.
+        user_property_revert_fns: Option<(
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+            ) -> sys::GDExtensionBool,
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+                r_ret: sys::GDExtensionVariantPtr,
+            ) -> sys::GDExtensionBool,
+        )>,
+
+        user_get_property_list_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                r_count: *mut u32,
+            ) -> *const sys::GDExtensionPropertyInfo,
+        >,
+
+        /// Only used since Godot 4.2.
+        user_validate_property_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_property: *mut sys::GDExtensionPropertyInfo,
+            ) -> sys::GDExtensionBool,
+        >,
+
         /// Callback for other virtuals.
         get_virtual_fn: unsafe extern "C" fn(
             p_userdata: *mut std::os::raw::c_void,
             p_name: sys::GDExtensionConstStringNamePtr,
         ) -> sys::GDExtensionClassCallVirtual,
     },
+
+    /// Collected from `#[godot_dyn] impl Trait for MyClass`. A class can have multiple of these, one per trait.
+    DynTraitImpl(DynTraitImpl),
 }
 
 /// Represents a class who is currently loaded and retained in memory.
@@ -203,6 +316,7 @@ struct ClassRegistrationInfo {
     register_methods_constants_fn: Option<ErasedRegisterFn>,
     register_properties_fn: Option<ErasedRegisterFn>,
     user_register_fn: Option<ErasedRegisterFn>,
+    register_rpcs_fn: Option<ErasedRegisterFn>,
     default_virtual_fn: sys::GDExtensionClassGetVirtual, // Option (set if there is at least one OnReady field)
     user_virtual_fn: sys::GDExtensionClassGetVirtual, // Option (set if there is a `#[godot_api] impl I*`)
 
@@ -231,6 +345,9 @@ impl ClassRegistrationInfo {
             PluginItem::Struct { .. } => 0,
             PluginItem::InherentImpl { .. } => 1,
             PluginItem::ITraitImpl { .. } => 2,
+            PluginItem::DynTraitImpl(_) => {
+                unreachable!("trait impls are not part of class registration")
+            }
         };
 
         if self.component_already_filled[index] {
@@ -320,6 +437,15 @@ pub fn auto_register_classes(init_level: InitLevel) {
         //out!("* Plugin: {elem:#?}");
 
         let name = elem.class_name;
+
+        // Trait impls are not part of the Godot class registration, and a class may have several of them.
+        if let PluginItem::DynTraitImpl(dyn_trait_impl) = &elem.item {
+            DYN_TRAIT_IMPLS
+                .lock()
+                .insert((name, dyn_trait_impl.dyn_trait_type_id), *dyn_trait_impl);
+            return;
+        }
+
         let class_info = map
             .entry(name)
             .or_insert_with(|| default_registration_info(name));
@@ -340,6 +466,12 @@ pub fn auto_register_classes(init_level: InitLevel) {
             .or_default()
             .push(loaded_class);
 
+        if let Some(register_rpcs_fn) = info.register_rpcs_fn {
+            RPC_REGISTRATIONS
+                .lock()
+                .insert(class_name, register_rpcs_fn);
+        }
+
         register_class_raw(info);
         out!("Class {class_name} loaded");
     }
@@ -358,6 +490,22 @@ pub fn unregister_classes(init_level: InitLevel) {
     }
 }
 
+/// Returns the function applying `#[rpc]` configurations to instances of the given class, if the class declares any RPCs.
+pub(crate) fn find_rpc_registration(class_name: ClassName) -> Option<ErasedRegisterFn> {
+    RPC_REGISTRATIONS.lock().get(&class_name).copied()
+}
+
+/// Returns the implementation of the trait object type with ID `dyn_trait_type_id` for the given class, if registered by `#[godot_dyn]`.
+pub(crate) fn find_dyn_trait_impl(
+    class_name: ClassName,
+    dyn_trait_type_id: TypeId,
+) -> Option<DynTraitImpl> {
+    DYN_TRAIT_IMPLS
+        .lock()
+        .get(&(class_name, dyn_trait_type_id))
+        .copied()
+}
+
 fn global_loaded_classes() -> GlobalGuard<'static, HashMap<InitLevel, Vec<LoadedClass>>> {
     match LOADED_CLASSES.try_lock() {
         Ok(it) => it,
@@ -385,6 +533,8 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             register_properties_fn,
             free_fn,
             default_get_virtual_fn,
+            default_property_can_revert_fn,
+            default_property_get_revert_fn,
             is_tool,
             is_editor_plugin,
             is_hidden,
@@ -406,6 +556,12 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             c.godot_params.is_abstract = (!is_instantiable) as sys::GDExtensionBool;
             c.godot_params.free_instance_func = Some(free_fn);
 
+            // A user-defined property_get_revert() may have been registered already, which takes precedence.
+            if c.godot_params.property_get_revert_func.is_none() {
+                c.godot_params.property_can_revert_func = default_property_can_revert_fn;
+                c.godot_params.property_get_revert_func = default_property_get_revert_fn;
+            }
+
             fill_into(
                 &mut c.godot_params.create_instance_func,
                 generated_create_fn,
@@ -439,8 +595,10 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
 
         PluginItem::InherentImpl {
             register_methods_constants_fn,
+            register_rpcs_fn,
         } => {
             c.register_methods_constants_fn = Some(register_methods_constants_fn);
+            c.register_rpcs_fn = register_rpcs_fn;
         }
 
         PluginItem::ITraitImpl {
@@ -451,6 +609,9 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             user_on_notification_fn,
             user_set_fn,
             user_get_fn,
+            user_property_revert_fns,
+            user_get_property_list_fn,
+            user_validate_property_fn,
             get_virtual_fn,
         } => {
             c.user_register_fn = user_register_fn;
@@ -472,8 +633,32 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             c.godot_params.notification_func = user_on_notification_fn;
             c.godot_params.set_func = user_set_fn;
             c.godot_params.get_func = user_get_fn;
+
+            // User-defined revert functions fall back to the #[init(default)] ones, so they replace them.
+            if let Some((can_revert_fn, get_revert_fn)) = user_property_revert_fns {
+                c.godot_params.property_can_revert_func = Some(can_revert_fn);
+                c.godot_params.property_get_revert_func = Some(get_revert_fn);
+            }
+
+            if user_get_property_list_fn.is_some() {
+                c.godot_params.get_property_list_func = user_get_property_list_fn;
+                c.godot_params.free_property_list_func = Some(callbacks::free_property_list);
+            }
+
+            #[cfg(since_api = "4.2")]
+            {
+                c.godot_params.validate_property_func = user_validate_property_fn;
+            }
+
+            #[cfg(before_api = "4.2")]
+            assert!(user_validate_property_fn.is_none()); // not used
+
             c.user_virtual_fn = Some(get_virtual_fn);
         }
+
+        PluginItem::DynTraitImpl(_) => {
+            unreachable!("trait impls are not part of class registration")
+        }
     }
     // out!("|   reg (after):     {c:?}");
     // out!();
@@ -504,6 +689,12 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
         info.godot_params.get_virtual_func = info.user_virtual_fn.or(info.default_virtual_fn);
     }
 
+    // Pass the #[rpc] configuration to the create/recreate callbacks as class userdata, so it is resolved once and not on every
+    // instantiation.
+    if let Some(register_rpcs_fn) = info.register_rpcs_fn {
+        info.godot_params.class_userdata = register_rpcs_fn.raw as *mut std::ffi::c_void;
+    }
+
     // The explicit () type notifies us if Godot API ever adds a return type.
     let registration_failed = unsafe {
         // Try to register class...
@@ -577,6 +768,11 @@ fn unregister_class_raw(class: LoadedClass) {
     let class_name = class.name;
     out!("Unregister class: {class_name}");
 
+    RPC_REGISTRATIONS.lock().remove(&class_name);
+    DYN_TRAIT_IMPLS
+        .lock()
+        .retain(|(impl_class_name, _), _| *impl_class_name != class_name);
+
     // If class is an editor plugin, unregister that first.
     #[cfg(since_api = "4.1")]
     if class.is_editor_plugin {
@@ -610,6 +806,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         register_methods_constants_fn: None,
         register_properties_fn: None,
         user_register_fn: None,
+        register_rpcs_fn: None,
         default_virtual_fn: None,
         user_virtual_fn: None,
         godot_params: default_creation_info(),
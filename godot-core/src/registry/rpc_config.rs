@@ -0,0 +1,54 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{StringName, VariantDictionary};
+use crate::dict;
+use crate::engine::multiplayer_api::RpcMode;
+use crate::engine::multiplayer_peer::TransferMode;
+use crate::engine::Node;
+use crate::obj::EngineEnum;
+
+/// Configuration of a remote procedure call, as accepted by [`Node::rpc_config()`].
+///
+/// Usually, you don't need to use this type directly: a `#[func]` annotated with `#[rpc]` is configured automatically whenever an
+/// instance of its class is created. The defaults match those of GDScript's `@rpc` annotation.
+#[derive(Copy, Clone, Debug)]
+pub struct RpcConfig {
+    pub rpc_mode: RpcMode,
+    pub transfer_mode: TransferMode,
+    pub call_local: bool,
+    pub channel: u32,
+}
+
+impl RpcConfig {
+    /// Registers this configuration for the method `method_name` on `node`.
+    pub fn configure_node(&self, node: &mut Node, method_name: impl Into<StringName>) {
+        node.rpc_config(method_name.into(), self.to_dictionary().to_variant());
+    }
+
+    /// Returns the dictionary representation expected by [`Node::rpc_config()`].
+    pub fn to_dictionary(&self) -> VariantDictionary {
+        dict! {
+            "rpc_mode": self.rpc_mode.ord(),
+            "transfer_mode": self.transfer_mode.ord(),
+            "call_local": self.call_local,
+            "channel": self.channel,
+        }
+    }
+}
+
+impl Default for RpcConfig {
+    fn default() -> Self {
+        Self {
+            rpc_mode: RpcMode::AUTHORITY,
+            transfer_mode: TransferMode::UNRELIABLE,
+            call_local: false,
+            channel: 0,
+        }
+    }
+}
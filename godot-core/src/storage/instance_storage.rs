@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::obj::{Base, Gd, GodotClass, Inherits};
+use crate::obj::{Base, BindError, Gd, GodotClass, Inherits};
 use crate::{godot_error, out};
 use godot_ffi as sys;
 
@@ -76,15 +76,21 @@ pub unsafe trait Storage {
     /// Returns a shared reference to this storage's instance.
     ///
     /// This will ensure Rust's rules surrounding references are upheld. Possibly panicking at runtime if
-    /// they are violated.
+    /// they are violated. In Debug mode, the panic message lists where the existing borrows were taken.
     fn get(&self) -> godot_cell::RefGuard<'_, Self::Instance>;
 
     /// Returns a mutable/exclusive reference to this storage's instance.
     ///
     /// This will ensure Rust's rules surrounding references are upheld. Possibly panicking at runtime if
-    /// they are violated.
+    /// they are violated. In Debug mode, the panic message lists where the existing borrows were taken.
     fn get_mut(&self) -> godot_cell::MutGuard<'_, Self::Instance>;
 
+    /// Like [`get()`][Self::get], but returns an error describing the existing borrows instead of panicking.
+    fn try_get(&self) -> Result<godot_cell::RefGuard<'_, Self::Instance>, BindError>;
+
+    /// Like [`get_mut()`][Self::get_mut], but returns an error describing the existing borrows instead of panicking.
+    fn try_get_mut(&self) -> Result<godot_cell::MutGuard<'_, Self::Instance>, BindError>;
+
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
     ///
     /// This can use the provided `instance` to provide extra safety guarantees such as allowing reentrant
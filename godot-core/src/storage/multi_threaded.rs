@@ -9,7 +9,7 @@ use std::any::type_name;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicU32, Ordering};
 
-use crate::obj::{Base, GodotClass};
+use crate::obj::{Base, BindError, GodotClass};
 use crate::out;
 use crate::storage::{AtomicLifecycle, Lifecycle, Storage, StorageRefCounted};
 
@@ -54,33 +54,50 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         &self.base
     }
 
+    #[track_caller]
     fn get(&self) -> godot_cell::RefGuard<'_, T> {
-        self.user_instance.as_ref().borrow().unwrap_or_else(|err| {
+        self.try_get().unwrap_or_else(|err| {
             panic!(
                 "\
                     Gd<T>::bind() failed, already bound; T = {}.\n  \
                     Make sure to use `self.base_mut()` or `self.base()` instead of `self.to_gd()` when possible.\n  \
-                    Details: {err}.\
+                    Details: {err}.{}\
                 ",
-                type_name::<T>()
+                type_name::<T>(),
+                err.format_borrow_sites(),
             )
         })
     }
 
+    #[track_caller]
     fn get_mut(&self) -> godot_cell::MutGuard<'_, T> {
-        self.user_instance
-            .as_ref()
-            .borrow_mut()
-            .unwrap_or_else(|err| {
-                panic!(
-                    "\
+        self.try_get_mut().unwrap_or_else(|err| {
+            panic!(
+                "\
                     Gd<T>::bind_mut() failed, already bound; T = {}.\n  \
                     Make sure to use `self.base_mut()` instead of `self.to_gd()` when possible.\n  \
-                    Details: {err}.\
+                    Details: {err}.{}\
                 ",
-                    type_name::<T>()
-                )
-            })
+                type_name::<T>(),
+                err.format_borrow_sites(),
+            )
+        })
+    }
+
+    #[track_caller]
+    fn try_get(&self) -> Result<godot_cell::RefGuard<'_, T>, BindError> {
+        self.user_instance
+            .as_ref()
+            .borrow()
+            .map_err(BindError::from_cell_error)
+    }
+
+    #[track_caller]
+    fn try_get_mut(&self) -> Result<godot_cell::MutGuard<'_, T>, BindError> {
+        self.user_instance
+            .as_ref()
+            .borrow_mut()
+            .map_err(BindError::from_cell_error)
     }
 
     fn get_inaccessible<'a: 'b, 'b>(
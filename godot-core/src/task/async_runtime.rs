@@ -0,0 +1,310 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use godot_ffi as sys;
+
+use crate::builtin::{Callable, Signal, Variant};
+use crate::engine::{Engine, SceneTree};
+
+/// Runs a future as a task on the main thread.
+///
+/// The future is polled once immediately. Afterwards, it is resumed whenever it is woken, e.g. when an awaited
+/// [`SignalFuture`][super::SignalFuture] resolves. Wake-ups are processed on the main thread; ones that cannot be handled immediately
+/// are deferred to the next frame (`SceneTree::process_frame`).
+///
+/// # Panics
+/// If called from a thread other than the main thread.
+pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+    assert!(
+        sys::is_main_thread(),
+        "godot::task::spawn() can only be called on the main thread"
+    );
+
+    let id = ASYNC_RUNTIME.with_borrow_mut(|rt| rt.add_task(Box::pin(future)));
+    poll_task(id);
+
+    TaskHandle {
+        id,
+        _not_send: PhantomData,
+    }
+}
+
+/// Handle to a task started with [`spawn()`].
+///
+/// Dropping the handle does not cancel the task; it continues running in the background.
+#[derive(Debug)]
+pub struct TaskHandle {
+    id: TaskId,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl TaskHandle {
+    /// Cancels the task, dropping its future.
+    ///
+    /// Has no effect if the task has already completed or been cancelled. If a task cancels itself, its future is dropped as soon as
+    /// it yields to the executor.
+    pub fn cancel(self) {
+        let removed = ASYNC_RUNTIME.with_borrow_mut(|rt| match &mut rt.current {
+            Some(current) if current.id == self.id => {
+                current.cancelled = true;
+                None
+            }
+            _ => rt.tasks.remove(&self.id),
+        });
+
+        // Drop outside the borrow, as the future's destructors may interact with the runtime.
+        drop(removed);
+    }
+
+    /// Returns `true` if the task has neither completed nor been cancelled.
+    pub fn is_pending(&self) -> bool {
+        ASYNC_RUNTIME.with_borrow(|rt| {
+            let is_current =
+                matches!(&rt.current, Some(current) if current.id == self.id && !current.cancelled);
+
+            is_current || rt.tasks.contains_key(&self.id)
+        })
+    }
+}
+
+/// Drops all tasks and disconnects from the frame loop. Called when the library is unloaded.
+pub(crate) fn cleanup() {
+    let (tasks, frame_hook) = ASYNC_RUNTIME.with_borrow_mut(|rt| {
+        let tasks = std::mem::take(&mut rt.tasks);
+        (tasks, rt.frame_hook.take())
+    });
+
+    if let Some(FrameHook { signal, callable }) = frame_hook {
+        FRAME_HOOK_CONNECTED.store(false, Ordering::Release);
+
+        if !signal.is_null() && signal.is_connected(callable.clone()) {
+            signal.disconnect(callable);
+        }
+    }
+
+    drop(tasks);
+}
+
+/// Connects to the frame loop if not yet done. Returns `false` if this is not possible, i.e. the main loop is not a `SceneTree`.
+///
+/// Must be called on the main thread.
+pub(crate) fn ensure_frame_hook() -> bool {
+    ASYNC_RUNTIME
+        .try_with(|rt| {
+            rt.try_borrow_mut()
+                .map_or(false, |mut rt| rt.ensure_frame_hook())
+        })
+        .unwrap_or(false)
+}
+
+/// Whether the frame loop is connected, which can be checked from any thread.
+pub(crate) fn is_frame_hook_connected() -> bool {
+    FRAME_HOOK_CONNECTED.load(Ordering::Acquire)
+}
+
+/// Marks the task currently being polled as cancelled. Returns `false` if no task is being polled.
+pub(crate) fn cancel_current_task() -> bool {
+    ASYNC_RUNTIME.with_borrow_mut(|rt| match &mut rt.current {
+        Some(current) => {
+            current.cancelled = true;
+            true
+        }
+        None => false,
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+type TaskId = u64;
+
+thread_local! {
+    static ASYNC_RUNTIME: RefCell<AsyncRuntime> = RefCell::new(AsyncRuntime::new());
+}
+
+/// Whether the main thread's runtime is connected to the frame loop.
+static FRAME_HOOK_CONNECTED: AtomicBool = AtomicBool::new(false);
+
+struct AsyncRuntime {
+    /// Pending tasks. The task currently being polled is temporarily removed.
+    tasks: HashMap<TaskId, Task>,
+    next_id: TaskId,
+    current: Option<CurrentTask>,
+    frame_hook: Option<FrameHook>,
+
+    /// Whether the warning about a missing `SceneTree` has been printed, so it is not repeated on every spawn.
+    warned_no_scene_tree: bool,
+}
+
+impl AsyncRuntime {
+    fn new() -> Self {
+        Self {
+            tasks: HashMap::new(),
+            next_id: 0,
+            current: None,
+            frame_hook: None,
+            warned_no_scene_tree: false,
+        }
+    }
+
+    fn add_task(&mut self, future: Pin<Box<dyn Future<Output = ()>>>) -> TaskId {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let waker = Arc::new(TaskWaker {
+            id,
+            woken: AtomicBool::new(true),
+        });
+        self.tasks.insert(id, Task { future, waker });
+
+        if !self.ensure_frame_hook() && !self.warned_no_scene_tree {
+            self.warned_no_scene_tree = true;
+            crate::godot_warn!(
+                "godot::task: main loop is not a SceneTree; deferred wake-ups of tasks are not processed"
+            );
+        }
+
+        id
+    }
+
+    /// Connects to `SceneTree::process_frame`, so that deferred wake-ups and closures queued for the main thread are processed once
+    /// per frame. Returns whether the connection exists.
+    fn ensure_frame_hook(&mut self) -> bool {
+        if self.frame_hook.is_some() {
+            return true;
+        }
+
+        let tree = Engine::singleton()
+            .get_main_loop()
+            .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok());
+
+        let Some(tree) = tree else {
+            return false;
+        };
+
+        let callable = Callable::from_fn("godot::task (frame loop)", |_args| {
+            poll_woken_tasks();
+            super::main_thread::flush_if_pending();
+            Ok(Variant::nil())
+        });
+
+        let signal = Signal::from_object_signal(&tree, "process_frame");
+        signal.connect(callable.clone(), 0);
+
+        self.frame_hook = Some(FrameHook { signal, callable });
+        FRAME_HOOK_CONNECTED.store(true, Ordering::Release);
+
+        true
+    }
+}
+
+struct Task {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+    waker: Arc<TaskWaker>,
+}
+
+struct CurrentTask {
+    id: TaskId,
+    cancelled: bool,
+}
+
+struct FrameHook {
+    signal: Signal,
+    callable: Callable,
+}
+
+struct TaskWaker {
+    id: TaskId,
+    woken: AtomicBool,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+
+        // Resume right away if possible; otherwise, the frame loop picks up the flag.
+        if sys::is_main_thread() {
+            poll_task(self.id);
+        }
+    }
+}
+
+fn poll_woken_tasks() {
+    let woken_ids: Vec<TaskId> = ASYNC_RUNTIME.with_borrow(|rt| {
+        rt.tasks
+            .iter()
+            .filter(|(_, task)| task.waker.woken.load(Ordering::Acquire))
+            .map(|(id, _)| *id)
+            .collect()
+    });
+
+    for id in woken_ids {
+        poll_task(id);
+    }
+}
+
+/// Polls the task with given ID, unless another task is currently being polled.
+fn poll_task(id: TaskId) {
+    // try_with: wake-ups may happen while thread-locals are destroyed.
+    let task = ASYNC_RUNTIME.try_with(|rt| {
+        let mut rt = rt.try_borrow_mut().ok()?;
+        if rt.current.is_some() {
+            return None;
+        }
+
+        let task = rt.tasks.remove(&id)?;
+        rt.current = Some(CurrentTask {
+            id,
+            cancelled: false,
+        });
+
+        Some(task)
+    });
+
+    let Ok(Some(mut task)) = task else {
+        return;
+    };
+
+    task.waker.woken.store(false, Ordering::Release);
+    let waker = Waker::from(task.waker.clone());
+    let mut context = Context::from_waker(&waker);
+
+    let result = crate::private::handle_panic(
+        || format!("godot::task: panic in task {id}"),
+        AssertUnwindSafe(|| task.future.as_mut().poll(&mut context)),
+    );
+
+    let finished_task = ASYNC_RUNTIME.with_borrow_mut(|rt| {
+        let current = rt.current.take().expect("current task set during poll");
+
+        match result {
+            Ok(Poll::Pending) if !current.cancelled => {
+                rt.tasks.insert(id, task);
+                None
+            }
+            // Completed, cancelled or panicked.
+            _ => Some(task),
+        }
+    });
+
+    // Drop outside the borrow, as the future's destructors may interact with the runtime.
+    drop(finished_task);
+}
@@ -0,0 +1,248 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::{CallContext, ParamTuple, VarcallSignatureTuple};
+use crate::builtin::{Callable, RustCallable, Signal, Variant};
+use crate::engine::object::ConnectFlags;
+use crate::godot_error;
+use crate::obj::EngineBitfield;
+
+/// Future that resolves with the arguments of the next emission of a signal.
+///
+/// Created via [`Signal::to_future()`][crate::builtin::Signal::to_future] or
+/// [`TypedSignal::to_future()`][crate::builtin::TypedSignal::to_future]. The output `R` is the tuple of signal parameters,
+/// e.g. `(i64, GString)`; each argument is converted via `FromGodot`.
+///
+/// If the object emitting the signal is freed before the signal is emitted, the task awaiting this future is cancelled, i.e. its future
+/// is dropped without being resumed. This requires the future to be awaited inside a task started with [`spawn()`][super::spawn].
+///
+/// If the emitted arguments cannot be converted to `R`, an error is printed and the awaiting task is cancelled in the same way.
+///
+/// Dropping the future before it resolves disconnects it from the signal.
+///
+/// The future is bound to the main thread: it can only be created there, and emissions of the signal on other threads are ignored.
+pub struct SignalFuture<R: ParamTuple> {
+    state: Arc<Mutex<ResolverState>>,
+    signal: Signal,
+    _params: PhantomData<fn() -> R>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl<R: ParamTuple> SignalFuture<R> {
+    pub(crate) fn new(signal: Signal) -> Self {
+        assert!(
+            sys::is_main_thread(),
+            "SignalFuture can only be created on the main thread"
+        );
+
+        let state = Arc::new(Mutex::new(ResolverState {
+            emission: Emission::Pending,
+            waker: None,
+        }));
+
+        // One-shot connection: Godot removes it after the first emission, dropping the resolver.
+        let callable = Callable::from_custom(SignalFutureResolver {
+            state: state.clone(),
+        });
+        signal.connect(callable, ConnectFlags::ONE_SHOT.ord() as i64);
+
+        Self {
+            state,
+            signal,
+            _params: PhantomData,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl<R: ParamTuple> Future for SignalFuture<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<R> {
+        let mut state = self.state.lock().unwrap();
+
+        match std::mem::replace(&mut state.emission, Emission::Consumed) {
+            Emission::Pending => {
+                state.emission = Emission::Pending;
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            Emission::Emitted(EmittedArgs(args)) => {
+                drop(state);
+
+                let signal_name = self.signal.name().to_string();
+                let call_ctx = CallContext::func("SignalFuture", &signal_name);
+                let arg_refs: Vec<&Variant> = args.iter().collect();
+
+                match R::Signature::params_from_variants(&call_ctx, &arg_refs) {
+                    Ok(params) => Poll::Ready(params),
+                    Err(err) => {
+                        godot_error!("SignalFuture: invalid arguments emitted by signal `{signal_name}`: {err}");
+                        self.cancel_task("emitted invalid arguments");
+
+                        Poll::Pending
+                    }
+                }
+            }
+            Emission::Dead => {
+                state.emission = Emission::Dead;
+                drop(state);
+
+                self.cancel_task("was freed");
+
+                Poll::Pending
+            }
+            Emission::Consumed => panic!("SignalFuture polled after completion"),
+        }
+    }
+}
+
+impl<R: ParamTuple> SignalFuture<R> {
+    /// Cancels the task awaiting this future, which can no longer resolve.
+    fn cancel_task(&self, reason: &str) {
+        let is_in_task = super::async_runtime::cancel_current_task();
+        assert!(
+            is_in_task,
+            "SignalFuture: object emitting signal `{}` {reason}; cannot cancel future outside of godot::task::spawn()",
+            self.signal.name()
+        );
+    }
+}
+
+impl<R: ParamTuple> Drop for SignalFuture<R> {
+    fn drop(&mut self) {
+        {
+            let mut state = self.state.lock().unwrap();
+            let emission = std::mem::replace(&mut state.emission, Emission::Consumed);
+            state.waker = None;
+
+            if !matches!(emission, Emission::Pending) || self.signal.is_null() {
+                return;
+            }
+        }
+
+        // Resolvers compare equal if they share the same state, so this identifies the connected callable.
+        let callable = Callable::from_custom(SignalFutureResolver {
+            state: self.state.clone(),
+        });
+
+        if self.signal.is_connected(callable.clone()) {
+            self.signal.disconnect(callable);
+        }
+    }
+}
+
+impl<R: ParamTuple> fmt::Debug for SignalFuture<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignalFuture")
+            .field("signal", &self.signal)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct ResolverState {
+    emission: Emission,
+    waker: Option<Waker>,
+}
+
+enum Emission {
+    Pending,
+    Emitted(EmittedArgs),
+
+    /// The connection was removed without emission, i.e. the emitting object was freed.
+    Dead,
+    Consumed,
+}
+
+/// Arguments of an emission, which are only ever created, accessed and dropped on the main thread.
+struct EmittedArgs(Vec<Variant>);
+
+// SAFETY: the resolver only stores arguments of emissions on the main thread (see SignalFutureResolver::invoke()). They are taken out
+// or dropped by SignalFuture::poll() or SignalFuture::drop(); SignalFuture is created on the main thread and is not Send, so this
+// happens on the main thread as well. The variants are thus never accessed from another thread.
+unsafe impl Send for EmittedArgs {}
+
+/// Callable connected to the signal, which stores the arguments and wakes the awaiting task.
+struct SignalFutureResolver {
+    state: Arc<Mutex<ResolverState>>,
+}
+
+impl PartialEq for SignalFutureResolver {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl Hash for SignalFutureResolver {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.state).hash(state);
+    }
+}
+
+impl fmt::Display for SignalFutureResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SignalFutureResolver")
+    }
+}
+
+impl RustCallable for SignalFutureResolver {
+    fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
+        if !sys::is_main_thread() {
+            godot_error!("SignalFuture: signal emitted on a thread other than the main thread; emission is ignored");
+            return Ok(Variant::nil());
+        }
+
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            if !matches!(state.emission, Emission::Pending) {
+                return Ok(Variant::nil());
+            }
+
+            let args = args.iter().map(|&arg| arg.clone()).collect();
+            state.emission = Emission::Emitted(EmittedArgs(args));
+            state.waker.take()
+        };
+
+        // Wake without holding the lock, as the task may be polled right away.
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+
+        Ok(Variant::nil())
+    }
+}
+
+impl Drop for SignalFutureResolver {
+    fn drop(&mut self) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            if !matches!(state.emission, Emission::Pending) {
+                return;
+            }
+
+            state.emission = Emission::Dead;
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
@@ -0,0 +1,184 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Mutex, MutexGuard, PoisonError};
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{Callable, Variant};
+
+/// Runs a closure on the main thread, without waiting for it.
+///
+/// Can be called from any thread, including the main thread itself. The closure is appended to a thread-safe queue, which is flushed on
+/// the main thread during the next frame (`SceneTree::process_frame`), similar to `call_deferred()`. Closures run in the order in which
+/// they were queued.
+///
+/// This is the way to interact with the scene tree from other threads. Use [`run_on_main_blocking()`] if you need the result.
+///
+/// Panics inside `f` are caught and printed, like panics in `#[func]` methods. If the library is unloaded before the queue is flushed,
+/// pending closures are dropped without being called.
+///
+/// # Panics
+/// If called from a thread other than the main thread without the `experimental-threads` Cargo feature, as scheduling the flush may
+/// interact with the engine.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::task;
+///
+/// std::thread::spawn(|| {
+///     let result = 6 * 7; // expensive computation
+///
+///     task::run_on_main(move || {
+///         godot_print!("result: {result}");
+///     });
+/// });
+/// ```
+pub fn run_on_main<F>(f: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    queue_on_main(Box::new(f));
+}
+
+/// Runs a closure on the main thread, blocks until it has completed, and returns its result.
+///
+/// If called on the main thread, closures queued before are run first to preserve their order, and then `f` is run immediately. Otherwise,
+/// `f` is queued like in [`run_on_main()`], and the calling thread is blocked until the main thread has processed it -- in the worst case,
+/// until the next frame.
+///
+/// # Panics
+/// - If `f` panics; the panic is propagated to the calling thread.
+/// - If the library is unloaded before `f` has run.
+/// - If called from a thread other than the main thread without the `experimental-threads` Cargo feature.
+///
+/// Do not call this from a thread that the main thread is waiting for (e.g. by joining it), as this leads to a deadlock.
+pub fn run_on_main_blocking<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    if sys::is_main_thread() {
+        flush_queue();
+        return f();
+    }
+
+    let (sender, receiver) = mpsc::sync_channel(1);
+    queue_on_main(Box::new(move || {
+        // Catch here instead of in the flush, so the calling thread can resume the panic.
+        let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+
+        // Receiver only disappears if the calling thread is gone, in which case nobody needs the result.
+        let _ = sender.send(result);
+    }));
+
+    match receiver.recv() {
+        Ok(Ok(value)) => value,
+        Ok(Err(panic_payload)) => std::panic::resume_unwind(panic_payload),
+        Err(_) => panic!("godot::task::run_on_main_blocking(): closure was dropped before it could run (library unloaded?)"),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Closures waiting to be run on the main thread. Shared between all threads.
+static QUEUE: Mutex<Vec<Job>> = Mutex::new(Vec::new());
+
+/// Whether closures have been queued since the last flush. Only modified while holding the `QUEUE` lock.
+static FLUSH_PENDING: AtomicBool = AtomicBool::new(false);
+
+/// Queues a closure to be run on the main thread, and schedules a flush of the queue if none is pending.
+fn queue_on_main(job: Job) {
+    #[cfg(not(feature = "experimental-threads"))]
+    assert!(
+        sys::is_main_thread(),
+        "godot::task: queuing closures from a thread other than the main thread requires the `experimental-threads` feature"
+    );
+
+    let needs_flush = {
+        let mut queue = lock_queue();
+        queue.push(job);
+
+        !FLUSH_PENDING.swap(true, Ordering::AcqRel)
+    };
+
+    if needs_flush {
+        schedule_flush();
+    }
+}
+
+/// Runs queued closures, if there are any. Called by the task runtime once per frame.
+pub(super) fn flush_if_pending() {
+    if FLUSH_PENDING.load(Ordering::Acquire) {
+        flush_queue();
+    }
+}
+
+/// Drops all closures that have not been run yet. Called when the library is unloaded.
+pub(crate) fn cleanup() {
+    let jobs = {
+        let mut queue = lock_queue();
+        FLUSH_PENDING.store(false, Ordering::Release);
+        std::mem::take(&mut *queue)
+    };
+
+    drop(jobs);
+}
+
+fn lock_queue() -> MutexGuard<'static, Vec<Job>> {
+    // Closures run outside the lock, so a poisoned mutex cannot leave the queue in an inconsistent state.
+    QUEUE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Makes sure the queue is flushed on the main thread.
+///
+/// The queue is flushed once per frame by the task runtime's frame hook. Until the hook is connected (which only the main thread can do),
+/// or if the main loop is not a `SceneTree`, a one-off flush goes through Godot's thread-safe message queue (`call_deferred`) instead.
+fn schedule_flush() {
+    let hook_connected = if sys::is_main_thread() {
+        super::async_runtime::ensure_frame_hook()
+    } else {
+        super::async_runtime::is_frame_hook_connected()
+    };
+
+    if hook_connected {
+        return;
+    }
+
+    let callable = Callable::from_fn("godot::task (main thread queue)", |_args| {
+        // Now on the main thread: connect the hook, so later closures do not need another deferred call.
+        super::async_runtime::ensure_frame_hook();
+        flush_queue();
+        Ok(Variant::nil())
+    });
+
+    // Vararg builtin methods are not generated, so go through the variant.
+    callable.to_variant().call("call_deferred", &[]);
+}
+
+fn flush_queue() {
+    // Take all jobs at once: closures queued while flushing are run by the next flush, instead of extending this one.
+    let jobs = {
+        let mut queue = lock_queue();
+        FLUSH_PENDING.store(false, Ordering::Release);
+        std::mem::take(&mut *queue)
+    };
+
+    for job in jobs {
+        let _ = crate::private::handle_panic(
+            || "godot::task: panic in closure run on main thread",
+            AssertUnwindSafe(job),
+        );
+    }
+}
@@ -0,0 +1,50 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Asynchronous tasks and closures on Godot's main thread.
+//!
+//! Rust futures can be run with [`spawn()`]. They are executed on the main thread, by an executor that is driven by the engine's
+//! frame loop. This makes it possible to await signals, similar to GDScript's `await`:
+//!
+//! ```no_run
+//! # use godot::prelude::*;
+//! # use godot::engine::Timer;
+//! use godot::task;
+//!
+//! fn start(timer: Gd<Timer>) {
+//!     task::spawn(async move {
+//!         let () = Signal::from_object_signal(&timer, "timeout").to_future().await;
+//!         godot_print!("timer expired");
+//!     });
+//! }
+//! ```
+//!
+//! Tasks are resumed as soon as they are woken on the main thread (e.g. directly during the emission of an awaited signal). Wake-ups
+//! from other threads, or while another task is running, are processed in the next frame.
+//!
+//! Other threads can hand work over to the main thread with [`run_on_main()`], or with [`run_on_main_blocking()`] if they need the result.
+
+#[cfg(since_api = "4.2")]
+mod async_runtime;
+#[cfg(since_api = "4.2")]
+mod futures;
+#[cfg(since_api = "4.2")]
+mod main_thread;
+
+#[cfg(since_api = "4.2")]
+pub use async_runtime::{spawn, TaskHandle};
+#[cfg(since_api = "4.2")]
+pub use futures::SignalFuture;
+#[cfg(since_api = "4.2")]
+pub use main_thread::{run_on_main, run_on_main_blocking};
+
+/// Drops pending tasks and queued closures. Called when the library is unloaded.
+#[cfg(since_api = "4.2")]
+pub(crate) fn cleanup() {
+    async_runtime::cleanup();
+    main_thread::cleanup();
+}
@@ -212,6 +212,14 @@ pub fn is_initialized() -> bool {
     BindingStorage::is_initialized()
 }
 
+/// Returns `true` if called on Godot's main thread, i.e. the thread which initialized the binding.
+///
+/// Returns `false` if the binding is not initialized.
+#[inline]
+pub fn is_main_thread() -> bool {
+    BindingStorage::is_main_thread()
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Crate-local implementation
 
@@ -11,12 +11,15 @@
 //!
 //! The user of these structs and functions must still ensure that multi-threaded usage of the various pointers is safe.
 
-use std::sync::OnceLock;
+use std::sync::{Mutex, OnceLock};
+use std::thread::ThreadId;
 
 use super::GodotBinding;
 use crate::ManualInitCell;
 
 pub(super) struct BindingStorage {
+    // Only used to tell whether we're on the main thread; the binding itself may be accessed from any thread.
+    main_thread_id: Mutex<Option<ThreadId>>,
     binding: ManualInitCell<GodotBinding>,
 }
 
@@ -25,6 +28,7 @@ impl BindingStorage {
     #[inline(always)]
     fn storage() -> &'static Self {
         static BINDING: BindingStorage = BindingStorage {
+            main_thread_id: Mutex::new(None),
             binding: ManualInitCell::new(),
         };
         &BINDING
@@ -43,6 +47,8 @@ impl BindingStorage {
             "initialize must only be called at startup or after deinitialize"
         );
 
+        *storage.main_thread_id.lock().unwrap() = Some(std::thread::current().id());
+
         // SAFETY: per declared invariants.
         unsafe { storage.binding.set(binding) }
     }
@@ -59,6 +65,8 @@ impl BindingStorage {
             "deinitialize must only be called after initialize"
         );
 
+        *storage.main_thread_id.lock().unwrap() = None;
+
         // SAFETY: per declared invariants.
         unsafe { storage.binding.clear() };
     }
@@ -85,6 +93,11 @@ impl BindingStorage {
         let storage = Self::storage();
         storage.binding.is_initialized()
     }
+
+    pub fn is_main_thread() -> bool {
+        let storage = Self::storage();
+        *storage.main_thread_id.lock().unwrap() == Some(std::thread::current().id())
+    }
 }
 
 pub struct GdextConfig {
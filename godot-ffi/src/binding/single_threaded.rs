@@ -113,6 +113,12 @@ impl BindingStorage {
         let storage = unsafe { Self::storage() };
         storage.main_thread_id.get().is_some()
     }
+
+    pub fn is_main_thread() -> bool {
+        // SAFETY: We don't access the binding.
+        let storage = unsafe { Self::storage() };
+        storage.main_thread_id.get() == Some(std::thread::current().id())
+    }
 }
 
 // SAFETY: We ensure that `binding` is only ever accessed from the same thread that initialized it.
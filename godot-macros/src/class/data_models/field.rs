@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::class::{FieldExport, FieldVar};
+use crate::class::{FieldExport, FieldGroup, FieldVar};
 use proc_macro2::{Ident, TokenStream};
 
 pub struct Field {
@@ -14,6 +14,9 @@ pub struct Field {
     pub default: Option<TokenStream>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
+    /// Group and subgroup starting at this field, from `#[export_group]` and `#[export_subgroup]`.
+    pub group: Option<FieldGroup>,
+    pub subgroup: Option<FieldGroup>,
     pub is_onready: bool,
 }
 
@@ -25,6 +28,8 @@ impl Field {
             default: None,
             var: None,
             export: None,
+            group: None,
+            subgroup: None,
             is_onready: false,
         }
     }
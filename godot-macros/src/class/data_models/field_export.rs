@@ -5,11 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Delimiter, Ident, TokenStream};
 use quote::quote;
 use std::collections::HashSet;
 
-use crate::util::{KvParser, ListParser};
+use crate::class::UsageFlags;
+use crate::util::{bail, ident, require_api_version, KvParser, ListParser};
 use crate::ParseResult;
 
 /// Store info from `#[export]` attribute.
@@ -84,6 +85,13 @@ pub enum FieldExport {
         kind: LayerKind,
     },
 
+    /// ### GDScript annotations
+    /// - `@export_flags_avoidance`
+    ///
+    /// ### Property hints
+    /// - `LAYERS_AVOIDANCE`
+    AvoidanceLayers,
+
     /// ### GDScript annotations
     /// - `@export_file`
     /// - `@export_global_file`
@@ -117,6 +125,41 @@ pub enum FieldExport {
     /// ### Property hints
     /// - `COLOR_NO_ALPHA`
     ColorNoAlpha,
+
+    /// ### GDScript annotations
+    /// - `@export_node_path`
+    ///
+    /// ### Property hints
+    /// - `NODE_PATH_VALID_TYPES`
+    NodePath { class_names: Vec<Ident> },
+
+    /// ### Property hints
+    /// - `EXPRESSION`
+    Expression,
+
+    /// ### GDScript annotations
+    /// - `@export_storage`
+    ///
+    /// ### Property usage
+    /// - `STORAGE`
+    Storage,
+
+    /// ### GDScript annotations
+    /// - `@export_custom`
+    Custom {
+        hint: Ident,
+        hint_string: Option<TokenStream>,
+        usage_flags: Option<Vec<Ident>>,
+    },
+
+    /// Hint for each element of an array or packed array, e.g. `#[export(element = (range = (0, 10)))]`.
+    ///
+    /// ### GDScript annotations
+    /// - Any of the above on an `Array[T]`, e.g. `@export_range(0, 10) var a: Array[int]`
+    ///
+    /// ### Property hints
+    /// - `TYPE_STRING`
+    Element { element: Box<FieldExport> },
 }
 
 impl FieldExport {
@@ -130,6 +173,7 @@ impl FieldExport {
     /// - `@export_{flags/enum}("elem1", "elem2:key2", ...)`
     ///   becomes
     ///   `#[export(flags/enum = (elem1, elem2 = key2, ...))]`
+    /// - `@export_{name}` on an array, applying to each element, becomes `#[export(element = (name))]`
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         if let Some(list_parser) = parser.handle_list("range")? {
             return Self::new_range_list(list_parser);
@@ -189,6 +233,11 @@ impl FieldExport {
             });
         }
 
+        if let Some(span) = parser.handle_alone_with_span("flags_avoidance")? {
+            require_api_version!("4.1", span, "#[export(flags_avoidance)]")?;
+            return Ok(Self::AvoidanceLayers);
+        }
+
         match parser.handle_any("file") {
             Some(None) => {
                 return Ok(Self::File {
@@ -251,9 +300,56 @@ impl FieldExport {
             return Ok(Self::ColorNoAlpha);
         }
 
+        if let Some(list_parser) = parser.handle_list("node_path")? {
+            return Self::new_node_path(list_parser);
+        }
+
+        if parser.handle_alone("expression")? {
+            return Ok(Self::Expression);
+        }
+
+        if parser.handle_alone("storage")? {
+            return Ok(Self::Storage);
+        }
+
+        if let Some(list_parser) = parser.handle_list("custom")? {
+            return Self::new_custom(list_parser);
+        }
+
+        if let Some(element_parser) = parser.handle_nested("element")? {
+            return Self::new_element(element_parser);
+        }
+
         Ok(FieldExport::Default)
     }
 
+    fn new_element(mut parser: KvParser) -> ParseResult<Self> {
+        let span = parser.span();
+        let element = Self::new_from_kv(&mut parser)?;
+        parser.finish()?;
+
+        match element {
+            FieldExport::Default | FieldExport::Storage => bail!(
+                span,
+                "#[export(element)] requires an export hint, e.g. `element = (range = (0, 10))`"
+            ),
+            FieldExport::Custom {
+                usage_flags: Some(_),
+                ..
+            } => bail!(
+                span,
+                "#[export(element)] does not support `usage_flags`; usage applies to the whole property"
+            ),
+            FieldExport::Element { .. } => bail!(
+                span,
+                "#[export(element)] cannot be nested, as Godot has no typed arrays of typed arrays"
+            ),
+            element => Ok(Self::Element {
+                element: Box::new(element),
+            }),
+        }
+    }
+
     fn new_range_list(mut parser: ListParser) -> ParseResult<FieldExport> {
         const ALLOWED_OPTIONS: [&str; 6] = [
             "or_greater",
@@ -348,6 +444,73 @@ impl FieldExport {
 
         Ok(Self::Flags { bits })
     }
+
+    fn new_node_path(mut parser: ListParser) -> ParseResult<Self> {
+        let mut class_names = Vec::new();
+
+        while let Some(class_name) = parser.next_ident()? {
+            class_names.push(class_name);
+        }
+
+        parser.finish()?;
+
+        Ok(Self::NodePath { class_names })
+    }
+
+    fn new_custom(mut parser: ListParser) -> ParseResult<Self> {
+        let mut hint = None;
+        let mut hint_string = None;
+        let mut usage_flags = None;
+
+        while let Some((key, value)) = parser.try_next_key_value() {
+            match key.to_string().as_str() {
+                "hint" if hint.is_none() => hint = Some(value.ident()?),
+                "hint_string" if hint_string.is_none() => hint_string = Some(value.expr()?),
+                "usage_flags" if usage_flags.is_none() => {
+                    let mut flags_parser =
+                        ListParser::new_from_tree(value.single()?, Delimiter::Bracket)?;
+                    let mut flags = Vec::new();
+
+                    while let Some(flag) = flags_parser.next_ident()? {
+                        flags.push(flag);
+                    }
+
+                    flags_parser.finish()?;
+                    usage_flags = Some(flags);
+                }
+                _ => {
+                    return bail!(
+                        key,
+                        "expected one of `hint`, `hint_string`, `usage_flags` (each at most once)"
+                    )
+                }
+            }
+        }
+
+        parser.finish()?;
+
+        let Some(hint) = hint else {
+            return bail!(parser.span_close(), "#[export(custom)] requires key `hint`");
+        };
+
+        Ok(Self::Custom {
+            hint,
+            hint_string,
+            usage_flags,
+        })
+    }
+
+    /// Usage flags of the property, if not overridden in `#[var]`.
+    pub fn usage_flags(&self) -> UsageFlags {
+        match self {
+            FieldExport::Storage => UsageFlags::Custom(vec![ident("STORAGE")]),
+            FieldExport::Custom {
+                usage_flags: Some(flags),
+                ..
+            } => UsageFlags::Custom(flags.clone()),
+            _ => UsageFlags::InferredExport,
+        }
+    }
 }
 
 macro_rules! quote_export_func {
@@ -359,9 +522,9 @@ macro_rules! quote_export_func {
 }
 
 impl FieldExport {
-    pub fn to_export_hint(&self) -> Option<TokenStream> {
+    pub fn to_export_hint(&self, field_type: &venial::TypeExpr) -> Option<TokenStream> {
         match self {
-            FieldExport::Default => None,
+            FieldExport::Default | FieldExport::Storage => None,
 
             FieldExport::Range {
                 min,
@@ -430,6 +593,8 @@ impl FieldExport {
                 kind: LayerKind::Navigation,
             } => quote_export_func! { export_flags_3d_navigation() },
 
+            FieldExport::AvoidanceLayers => quote_export_func! { export_flags_avoidance() },
+
             FieldExport::File {
                 global: false,
                 kind: FileKind::Dir,
@@ -449,16 +614,137 @@ impl FieldExport {
                 quote_export_func! { export_file_inner(#global, #filter) }
             }
 
-            FieldExport::Multiline => quote_export_func! { export_multiline() },
+            FieldExport::Multiline => {
+                make_element_hint(field_type, quote! { String }, quote! { export_multiline() })
+            }
 
             FieldExport::PlaceholderText { placeholder } => quote_export_func! {
                 export_placeholder(#placeholder)
             },
-            FieldExport::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+            FieldExport::ColorNoAlpha => make_element_hint(
+                field_type,
+                quote! { Color },
+                quote! { export_color_no_alpha() },
+            ),
+
+            FieldExport::NodePath { class_names } => {
+                let class_names = class_names.iter().map(|name| name.to_string());
+
+                quote_export_func! {
+                    export_node_path(&[#(#class_names),*] as &[&str])
+                }
+            }
+
+            FieldExport::Expression => quote_export_func! { export_expression() },
+
+            FieldExport::Custom {
+                hint, hint_string, ..
+            } => {
+                let hint_string = hint_string.clone().unwrap_or(quote! { "" });
+
+                quote_export_func! {
+                    export_custom(::godot::engine::global::PropertyHint::#hint, #hint_string)
+                }
+            }
+
+            FieldExport::Element { element } => {
+                let element_type = venial::TypeExpr {
+                    tokens: quote! { <#field_type as ::godot::register::property::ElementExport>::Element }
+                        .into_iter()
+                        .collect(),
+                };
+                let element_hint = element
+                    .to_export_hint(&element_type)
+                    .expect("element hint validated during parsing");
+
+                Some(quote! {
+                    ::godot::register::property::export_info_functions::export_element::<#field_type>(#element_hint)
+                })
+            }
+        }
+    }
+}
+
+/// Hint for annotations that apply to either a single value of `element_type`, or to each element of an array.
+fn make_element_hint(
+    field_type: &venial::TypeExpr,
+    element_type: TokenStream,
+    element_hint_fn: TokenStream,
+) -> Option<TokenStream> {
+    Some(quote! {
+        ::godot::register::property::export_info_functions::export_element_hint::<#field_type>(
+            ::godot::builtin::VariantType::#element_type,
+            ::godot::register::property::export_info_functions::#element_hint_fn,
+        )
+    })
+}
+
+/// Store info from `#[export_group]` and `#[export_subgroup]` attributes.
+///
+/// ### GDScript annotations
+/// - `@export_group`
+/// - `@export_subgroup`
+///
+/// ### Property usage
+/// - `GROUP`
+/// - `SUBGROUP`
+pub struct FieldGroup {
+    pub kind: GroupKind,
+    pub name: TokenStream,
+    pub prefix: Option<TokenStream>,
+}
+
+impl FieldGroup {
+    /// Parse an `#[export_group(..)]` or `#[export_subgroup(..)]` attribute.
+    ///
+    /// - `@export_group("Name")` becomes `#[export_group(name = "Name")]`
+    /// - `@export_group("Name", "prefix_")` becomes `#[export_group(name = "Name", prefix = "prefix_")]`
+    ///
+    /// An empty name ends the current group, like in GDScript.
+    pub(crate) fn new_from_kv(parser: &mut KvParser, kind: GroupKind) -> ParseResult<Self> {
+        let name = parser.handle_expr_required("name")?;
+        let prefix = parser.handle_expr("prefix")?;
+
+        Ok(Self { kind, name, prefix })
+    }
+
+    /// Registers the group with Godot. Must run right before registering the first property of the group.
+    pub fn to_registration(&self, class_name_obj: &TokenStream) -> TokenStream {
+        let FieldGroup { kind, name, prefix } = self;
+
+        let register_fn = match kind {
+            GroupKind::Group => quote! { classdb_register_extension_class_property_group },
+            GroupKind::Subgroup => quote! { classdb_register_extension_class_property_subgroup },
+        };
+        let prefix = prefix.clone().unwrap_or(quote! { "" });
+
+        quote! {
+            {
+                use ::godot::sys::GodotFfi;
+
+                let group_name = ::godot::builtin::GString::from(#name);
+                let prefix = ::godot::builtin::GString::from(#prefix);
+
+                unsafe {
+                    ::godot::sys::interface_fn!(#register_fn)(
+                        ::godot::sys::get_library(),
+                        #class_name_obj.string_sys(),
+                        group_name.string_sys(),
+                        prefix.string_sys(),
+                    );
+                }
+            }
         }
     }
 }
 
+/// Whether a `FieldGroup` is a top-level group or nested in one.
+#[derive(Copy, Clone)]
+pub enum GroupKind {
+    Group,
+    Subgroup,
+}
+
 /// The dimension of a `@export_flags_{dimension}_{layer}` annotation.
 pub enum LayerDimension {
     _2d,
@@ -12,7 +12,7 @@ use crate::class::{
     into_signature_info, make_existence_check, make_method_registration, Field, FieldHint,
     FuncDefinition,
 };
-use crate::util::KvParser;
+use crate::util::{bail, KvParser};
 use crate::{util, ParseResult};
 
 /// Store info from `#[var]` attribute.
@@ -22,6 +22,7 @@ pub struct FieldVar {
     pub setter: GetterSetter,
     pub hint: FieldHint,
     pub usage_flags: UsageFlags,
+    pub setter_hooks: SetterHooks,
 }
 
 impl FieldVar {
@@ -33,6 +34,9 @@ impl FieldVar {
     /// - `hint = ident`
     /// - `hint_string = expr`
     /// - `usage_flags =
+    /// - `validate = ident`
+    /// - `on_change = ident`
+    /// - `notify_property_list_changed`
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let mut getter = GetterSetter::parse(parser, "get")?;
         let mut setter = GetterSetter::parse(parser, "set")?;
@@ -66,15 +70,49 @@ impl FieldVar {
             UsageFlags::Inferred
         };
 
+        let setter_hooks = SetterHooks {
+            validate: parser.handle_ident("validate")?,
+            on_change: parser.handle_ident("on_change")?,
+            notify_property_list_changed: parser.handle_alone("notify_property_list_changed")?,
+        };
+
+        // Hooks are invoked by the generated setter; a hand-written setter can call them itself.
+        if !setter_hooks.is_empty() && setter != GetterSetter::Generated {
+            return bail!(
+                parser.span(),
+                "#[var] keys `validate`, `on_change` and `notify_property_list_changed` require a generated setter"
+            );
+        }
+
         Ok(FieldVar {
             getter,
             setter,
             hint,
             usage_flags,
+            setter_hooks,
         })
     }
 }
 
+/// Functions called by a generated setter, around the assignment of the field.
+#[derive(Default, Clone, Debug)]
+pub struct SetterHooks {
+    /// `fn(&self, value: Via) -> Via`, which may adjust the value before it is assigned.
+    pub validate: Option<Ident>,
+
+    /// `fn(&mut self)`, called after the value has been assigned.
+    pub on_change: Option<Ident>,
+
+    /// Whether to call `notify_property_list_changed()` on the base object after the value has been assigned.
+    pub notify_property_list_changed: bool,
+}
+
+impl SetterHooks {
+    fn is_empty(&self) -> bool {
+        self.validate.is_none() && self.on_change.is_none() && !self.notify_property_list_changed
+    }
+}
+
 #[derive(Default, Clone, Eq, PartialEq, Debug)]
 pub enum GetterSetter {
     /// Getter/setter should be omitted, field is write/read only.
@@ -176,11 +214,36 @@ impl GetterSetterImpl {
                 };
             }
             GetSet::Set => {
+                let SetterHooks {
+                    validate,
+                    on_change,
+                    notify_property_list_changed,
+                } = field
+                    .var
+                    .as_ref()
+                    .map(|var| var.setter_hooks.clone())
+                    .unwrap_or_default();
+
+                let validate = validate.map(|validate| {
+                    quote! { let #field_name = self.#validate(#field_name); }
+                });
+                let on_change = on_change.map(|on_change| {
+                    quote! { self.#on_change(); }
+                });
+                let notify = notify_property_list_changed.then(|| {
+                    quote! {
+                        ::godot::obj::WithBaseField::base_mut(self).notify_property_list_changed();
+                    }
+                });
+
                 signature = quote! {
                     fn #function_name(&mut self, #field_name: <#field_type as ::godot::builtin::meta::GodotConvert>::Via)
                 };
                 function_body = quote! {
+                    #validate
                     <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
+                    #on_change
+                    #notify
                 };
             }
         }
@@ -206,6 +269,9 @@ impl GetterSetterImpl {
                 rename: None,
                 is_script_virtual: false,
                 has_gd_self: false,
+                default_parameters: Vec::new(),
+                is_variadic: false,
+                result_fallback: None,
             },
         );
 
@@ -5,10 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::util::{bail_fn, ident};
+use crate::util::{bail, bail_fn, ident, KvParser};
 use crate::{util, ParseResult};
 use proc_macro2::{Group, Ident, TokenStream, TokenTree};
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 
 /// Information used for registering a Rust function with Godot.
 pub struct FuncDefinition {
@@ -22,6 +22,12 @@ pub struct FuncDefinition {
     pub rename: Option<String>,
     pub is_script_virtual: bool,
     pub has_gd_self: bool,
+    /// Default values of the trailing parameters declared with `#[opt(default = ...)]`, in order.
+    pub default_parameters: Vec<TokenStream>,
+    /// Whether the function ends in a `&[&Variant]` parameter, which receives all arguments beyond the regular parameters.
+    pub is_variadic: bool,
+    /// If the function returns `Result`, the expression returned to Godot in case of `Err`.
+    pub result_fallback: Option<TokenStream>,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -35,7 +41,8 @@ pub fn make_virtual_callback(
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
 
-    let wrapped_method = make_forwarding_closure(class_name, &signature_info, before_kind);
+    let wrapped_method =
+        make_forwarding_closure(class_name, &signature_info, before_kind, false, None);
     let sig_tuple = signature_info.tuple_type();
 
     let call_ctx = make_call_context(
@@ -68,16 +75,36 @@ pub fn make_method_registration(
     func_definition: FuncDefinition,
 ) -> ParseResult<TokenStream> {
     let signature_info = &func_definition.signature_info;
-    let sig_tuple = signature_info.tuple_type();
+    let result_fallback = func_definition.result_fallback.as_ref();
+
+    // `Result` is converted to a variant by the forwarding closure, so Godot sees a `Variant` return type.
+    let sig_tuple = if result_fallback.is_some() {
+        util::make_signature_tuple_type(
+            &quote! { ::godot::builtin::Variant },
+            &signature_info.param_types,
+        )
+    } else {
+        signature_info.tuple_type()
+    };
 
     let is_script_virtual = func_definition.is_script_virtual;
-    let method_flags = match make_method_flags(signature_info.receiver_type, is_script_virtual) {
+    let method_flags = match make_method_flags(
+        signature_info.receiver_type,
+        is_script_virtual,
+        func_definition.is_variadic,
+    ) {
         Ok(mf) => mf,
         Err(msg) => return bail_fn(msg, &signature_info.method_name),
     };
 
-    let forwarding_closure =
-        make_forwarding_closure(class_name, signature_info, BeforeKind::Without);
+    let is_variadic = func_definition.is_variadic;
+    let forwarding_closure = make_forwarding_closure(
+        class_name,
+        signature_info,
+        BeforeKind::Without,
+        is_variadic,
+        result_fallback,
+    );
 
     // String literals
     let method_name = &signature_info.method_name;
@@ -88,9 +115,23 @@ pub fn make_method_registration(
         method_name.to_string()
     };
 
+    let (default_values_fn_decl, default_values, default_arguments) = make_default_values(
+        class_name,
+        signature_info,
+        &func_definition.default_parameters,
+    );
+
     let call_ctx = make_call_context(&class_name_str, &method_name_str);
-    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure);
-    let ptrcall_fn_decl = make_ptrcall_fn(&call_ctx, &forwarding_closure);
+    let varcall_fn_decl =
+        make_varcall_fn(&call_ctx, &forwarding_closure, &default_values, is_variadic);
+
+    // Godot never ptrcalls variadic methods.
+    let (ptrcall_fn_decl, ptrcall_fn) = if is_variadic {
+        (TokenStream::new(), quote! { None })
+    } else {
+        let decl = make_ptrcall_fn(&call_ctx, &forwarding_closure);
+        (quote! { #decl; }, quote! { Some(ptrcall_fn) })
+    };
 
     // String literals II
     let param_ident_strs = signature_info
@@ -116,8 +157,10 @@ pub fn make_method_registration(
 
             let method_name = StringName::from(#method_name_str);
 
+            #default_values_fn_decl
+
             #varcall_fn_decl;
-            #ptrcall_fn_decl;
+            #ptrcall_fn_decl
 
             // SAFETY:
             // `get_varcall_func` upholds all the requirements for `call_func`.
@@ -127,12 +170,12 @@ pub fn make_method_registration(
                     #class_name::class_name(),
                     method_name,
                     Some(varcall_fn),
-                    Some(ptrcall_fn),
+                    #ptrcall_fn,
                     #method_flags,
                     &[
                         #( #param_ident_strs ),*
                     ],
-                    Vec::new()
+                    #default_arguments
                 )
             };
 
@@ -161,7 +204,7 @@ pub enum ReceiverType {
     Static,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct SignatureInfo {
     pub method_name: Ident,
     pub receiver_type: ReceiverType,
@@ -199,14 +242,25 @@ pub enum BeforeKind {
 }
 
 /// Returns a closure expression that forwards the parameters to the Rust instance.
+///
+/// For variadic functions, the closure takes the additional arguments as a third parameter and passes them last.
+/// If `result_fallback` is set, the method returns `Result`, which the closure converts to a variant.
 fn make_forwarding_closure(
     class_name: &Ident,
     signature_info: &SignatureInfo,
     before_kind: BeforeKind,
+    is_variadic: bool,
+    result_fallback: Option<&TokenStream>,
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
 
+    let (varargs_param, varargs_arg) = if is_variadic {
+        (quote! { , varargs }, quote! { varargs })
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
     let instance_decl = match &signature_info.receiver_type {
         ReceiverType::Ref => quote! {
             let instance = ::godot::private::Storage::get(storage);
@@ -232,11 +286,12 @@ fn make_forwarding_closure(
             let method_call = if matches!(before_kind, BeforeKind::OnlyBefore) {
                 TokenStream::new()
             } else {
-                quote! { instance.#method_name( #(#params),* ) }
+                let method_call = quote! { instance.#method_name( #(#params,)* #varargs_arg ) };
+                wrap_result(method_call, class_name, signature_info, result_fallback)
             };
 
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #varargs_param| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
@@ -251,30 +306,89 @@ fn make_forwarding_closure(
         ReceiverType::GdSelf => {
             // Method call is always present, since GdSelf implies that the user declares the method.
             // (Absent method is only used in the case of a generated default virtual method, e.g. for ready()).
+            let method_call = wrap_result(
+                quote! {
+                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params,)* #varargs_arg)
+                },
+                class_name,
+                signature_info,
+                result_fallback,
+            );
+
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #varargs_param| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    #method_call
                 }
             }
         }
         ReceiverType::Static => {
             // No before-call needed, since static methods are not virtual.
+            let method_call = wrap_result(
+                quote! { #class_name::#method_name(#(#params,)* #varargs_arg) },
+                class_name,
+                signature_info,
+                result_fallback,
+            );
+
             quote! {
-                |_, params| {
+                |_, params #varargs_param| {
                     let ( #(#params,)* ) = params;
-                    #class_name::#method_name(#(#params),*)
+                    #method_call
                 }
             }
         }
     }
 }
 
+/// If the method returns `Result`, converts the result of `method_call` to a variant; otherwise returns `method_call` unchanged.
+fn wrap_result(
+    method_call: TokenStream,
+    class_name: &Ident,
+    signature_info: &SignatureInfo,
+    result_fallback: Option<&TokenStream>,
+) -> TokenStream {
+    let Some(fallback) = result_fallback else {
+        return method_call;
+    };
+
+    let method_name = &signature_info.method_name;
+    let function_name = format!("{class_name}::{method_name}");
+    let fallback: TokenStream = map_self_to_class_name(fallback.clone(), class_name);
+
+    // Spanned, so that file!() and line!() point to the user's method.
+    let location = quote_spanned! { method_name.span()=> file!(), line!() };
+
+    quote! {
+        ::godot::private::func_result_to_variant(
+            #method_call,
+            || ::godot::builtin::meta::ToGodot::to_variant(&#fallback),
+            #function_name,
+            #location,
+        )
+    }
+}
+
+/// Returns whether `ty` is a `Result<...>` type, possibly qualified with a path.
+pub fn is_result_type(ty: &TokenStream) -> bool {
+    let mut last_ident = None;
+    for tt in ty.clone() {
+        match tt {
+            TokenTree::Ident(ident) => last_ident = Some(ident),
+            TokenTree::Punct(punct) if punct.as_char() == '<' => break,
+            TokenTree::Punct(punct) if punct.as_char() == ':' => {}
+            _ => return false,
+        }
+    }
+
+    last_ident.map_or(false, |ident| ident == "Result")
+}
+
 /// Maps each usage of `Self` to the struct it's referencing,
 /// since `Self` can't be used inside nested functions.
 fn map_self_to_class_name<In, Out>(tokens: In, class_name: &Ident) -> Out
@@ -378,6 +492,7 @@ pub(crate) fn maybe_rename_parameter(param_ident: Ident, next_unnamed_index: &mu
 fn make_method_flags(
     method_type: ReceiverType,
     is_script_virtual: bool,
+    is_variadic: bool,
 ) -> Result<TokenStream, String> {
     let flags = quote! { ::godot::engine::global::MethodFlags };
 
@@ -405,12 +520,151 @@ fn make_method_flags(
         base_flags
     };
 
+    let flags = if is_variadic {
+        quote! { #flags | ::godot::engine::global::MethodFlags::VARARG }
+    } else {
+        flags
+    };
+
     Ok(flags)
 }
 
+/// If the last parameter of `signature` has type `&[&Variant]`, removes it and returns `true`.
+///
+/// Such a parameter makes the function variadic: it receives all arguments beyond the regular ones.
+pub fn remove_varargs_parameter(signature: &mut venial::Function) -> bool {
+    let Some((venial::FnParam::Typed(param), _)) = signature.params.inner.last() else {
+        return false;
+    };
+
+    let ty: String = param
+        .ty
+        .to_token_stream()
+        .to_string()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    let is_varargs = ty
+        .strip_prefix("&[&")
+        .and_then(|ty| ty.strip_suffix(']'))
+        .map_or(false, |elem| {
+            elem == "Variant" || elem.ends_with("::Variant")
+        });
+
+    if is_varargs {
+        signature.params.inner.pop();
+    }
+
+    is_varargs
+}
+
+/// Extracts `#[opt(default = ...)]` attributes from the parameters of `function`, removing them.
+///
+/// Returns the default values of the trailing parameters. Parameters following one with a default must have defaults as well.
+pub fn extract_default_parameters(
+    function: &mut venial::Function,
+    has_gd_self: bool,
+) -> ParseResult<Vec<TokenStream>> {
+    let mut defaults = Vec::new();
+    let mut first_typed_param = true;
+
+    for (param, _) in function.params.inner.iter_mut() {
+        let venial::FnParam::Typed(param) = param else {
+            continue;
+        };
+
+        let default = match KvParser::parse(&param.attributes, "opt")? {
+            Some(mut parser) => {
+                let default = parser.handle_expr_required("default")?;
+                parser.finish()?;
+                Some(default)
+            }
+            None => None,
+        };
+
+        param.attributes.retain(|attr| {
+            attr.get_single_path_segment()
+                .map_or(true, |name| name != "opt")
+        });
+
+        // Gd<Self> parameter is provided by the engine, not part of the Godot signature.
+        let is_gd_self = has_gd_self && first_typed_param;
+        first_typed_param = false;
+
+        match (default, is_gd_self) {
+            (Some(_), true) => {
+                return bail!(&param.name, "#[opt] is not allowed on the `gd_self` parameter")
+            }
+            (_, true) => {}
+            (Some(default), false) => defaults.push(default),
+            (None, false) if !defaults.is_empty() => {
+                return bail!(
+                    &param.name,
+                    "parameters following one with #[opt(default = ...)] must also have a default value"
+                )
+            }
+            (None, false) => {}
+        }
+    }
+
+    Ok(defaults)
+}
+
+/// Returns a function returning the default values, the expression to pass to `in_varcall()`, and the expression for registration.
+fn make_default_values(
+    class_name: &Ident,
+    signature_info: &SignatureInfo,
+    default_parameters: &[TokenStream],
+) -> (TokenStream, TokenStream, TokenStream) {
+    if default_parameters.is_empty() {
+        return (TokenStream::new(), quote! { &[] }, quote! { Vec::new() });
+    }
+
+    // Defaults belong to the last parameters.
+    let param_count = signature_info.param_types.len();
+    let default_types = &signature_info.param_types[param_count - default_parameters.len()..];
+    let default_exprs = default_parameters
+        .iter()
+        .map(|expr| map_self_to_class_name::<_, TokenStream>(expr.clone(), class_name));
+
+    // Evaluated again for each call that relies on them, similar to GDScript. Type annotation checks the value against the parameter.
+    let fn_decl = quote! {
+        fn default_values() -> Vec<Variant> {
+            vec![
+                #(
+                    {
+                        let value: #default_types = #default_exprs;
+                        ::godot::builtin::meta::ToGodot::to_variant(&value)
+                    },
+                )*
+            ]
+        }
+    };
+
+    let varcall_defaults = quote! {
+        &if (arg_count as usize) < <Sig as ::godot::builtin::meta::VarcallSignatureTuple>::PARAM_COUNT {
+            default_values()
+        } else {
+            Vec::new()
+        }
+    };
+
+    (fn_decl, varcall_defaults, quote! { default_values() })
+}
+
 /// Generate code for a C FFI function that performs a varcall.
-fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> TokenStream {
-    let invocation = make_varcall_invocation(wrapped_method);
+fn make_varcall_fn(
+    call_ctx: &TokenStream,
+    wrapped_method: &TokenStream,
+    default_values: &TokenStream,
+    is_variadic: bool,
+) -> TokenStream {
+    let invocation = if is_variadic {
+        make_variadic_varcall_invocation(wrapped_method)
+    } else {
+        make_varcall_invocation(wrapped_method, default_values)
+    };
 
     // TODO reduce amount of code generated, by delegating work to a library function. Could even be one that produces this function pointer.
     quote! {
@@ -477,9 +731,28 @@ fn make_ptrcall_invocation(wrapped_method: &TokenStream, is_virtual: bool) -> To
 }
 
 /// Generate code for a `varcall()` call expression.
-fn make_varcall_invocation(wrapped_method: &TokenStream) -> TokenStream {
+fn make_varcall_invocation(
+    wrapped_method: &TokenStream,
+    default_values: &TokenStream,
+) -> TokenStream {
     quote! {
         <Sig as ::godot::builtin::meta::VarcallSignatureTuple>::in_varcall(
+            instance_ptr,
+            &call_ctx,
+            args_ptr,
+            arg_count,
+            #default_values,
+            ret,
+            err,
+            #wrapped_method,
+        )
+    }
+}
+
+/// Generate code for a `varcall()` call expression of a variadic function.
+fn make_variadic_varcall_invocation(wrapped_method: &TokenStream) -> TokenStream {
+    quote! {
+        <Sig as ::godot::builtin::meta::VarcallSignatureTuple>::in_varcall_variadic(
             instance_ptr,
             &call_ctx,
             args_ptr,
@@ -6,8 +6,10 @@
  */
 
 use crate::class::{
-    into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, SignalDefinition, SignatureInfo,
+    extract_default_parameters, into_signature_info, is_result_type, make_constant_registration,
+    make_method_registration, make_rpc_helpers, make_rpc_registrations_fn, make_signal_collection,
+    make_signal_registrations, parse_rpc_attribute, remove_varargs_parameter, ConstDefinition,
+    FuncDefinition, ReceiverType, RpcDefinition, SignalDefinition, SignatureInfo,
 };
 use crate::util::{bail, require_api_version, KvParser};
 use crate::{util, ParseResult};
@@ -22,6 +24,7 @@ enum ItemAttrType {
         rename: Option<String>,
         is_virtual: bool,
         has_gd_self: bool,
+        error_fallback: Option<TokenStream>,
     },
     Signal(venial::AttributeValue),
     Const(#[allow(dead_code)] venial::AttributeValue),
@@ -48,10 +51,22 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
     let prv = quote! { ::godot::private };
 
     // Can add extra functions to the end of the impl block.
-    let (funcs, signals) = process_godot_fns(&class_name, &mut impl_block)?;
+    let (funcs, signals, rpcs) = process_godot_fns(&class_name, &mut impl_block)?;
     let consts = process_godot_constants(&mut impl_block)?;
 
-    let signal_registrations = make_signal_registrations(signals, &class_name_obj);
+    let signal_registrations = make_signal_registrations(&signals, &class_name_obj);
+    let signal_collection = make_signal_collection(&class_name, &signals);
+
+    let rpc_registrations = make_rpc_registrations_fn(&rpcs);
+    let rpc_helpers = make_rpc_helpers(&class_name, &rpcs);
+    let register_rpcs_fn = match rpc_registrations {
+        Some(_) => quote! {
+            Some(#prv::ErasedRegisterFn {
+                raw: #prv::callbacks::register_user_rpcs::<#class_name>,
+            })
+        },
+        None => quote! { None },
+    };
 
     let method_registrations: Vec<TokenStream> = funcs
         .into_iter()
@@ -63,6 +78,10 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
     let result = quote! {
         #impl_block
 
+        #signal_collection
+
+        #rpc_helpers
+
         impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
             fn __register_methods() {
                 #( #method_registrations )*
@@ -72,6 +91,8 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
             fn __register_constants() {
                 #constant_registration
             }
+
+            #rpc_registrations
         }
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
@@ -80,6 +101,7 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
                 register_methods_constants_fn: #prv::ErasedRegisterFn {
                     raw: #prv::callbacks::register_user_methods_constants::<#class_name>,
                 },
+                register_rpcs_fn: #register_rpcs_fn,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
         });
@@ -91,9 +113,14 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
 fn process_godot_fns(
     class_name: &Ident,
     impl_block: &mut venial::Impl,
-) -> ParseResult<(Vec<FuncDefinition>, Vec<SignalDefinition>)> {
+) -> ParseResult<(
+    Vec<FuncDefinition>,
+    Vec<SignalDefinition>,
+    Vec<RpcDefinition>,
+)> {
     let mut func_definitions = vec![];
     let mut signal_definitions = vec![];
+    let mut rpc_definitions = vec![];
     let mut virtual_functions = vec![];
 
     let mut removed_indexes = vec![];
@@ -103,12 +130,22 @@ fn process_godot_fns(
         };
 
         let Some(attr) = extract_attributes(&function, &function.attributes)? else {
+            if has_rpc_attribute(function) {
+                return bail!(
+                    &function.name,
+                    "#[rpc] can only be used together with #[func]"
+                );
+            }
             continue;
         };
 
         // Remaining code no longer has attribute -- rest stays
         function.attributes.remove(attr.index);
 
+        if !matches!(attr.ty, ItemAttrType::Func { .. }) && has_rpc_attribute(function) {
+            return attr.bail("#[rpc] can only be used together with #[func]", function);
+        }
+
         if function.qualifiers.tk_default.is_some()
             || function.qualifiers.tk_const.is_some()
             || function.qualifiers.tk_async.is_some()
@@ -128,7 +165,11 @@ fn process_godot_fns(
                 rename,
                 is_virtual,
                 has_gd_self,
+                error_fallback,
             } => {
+                // Must happen before collecting external attributes and the signature, as it removes #[rpc] and #[opt].
+                let rpc_attr = parse_rpc_attribute(function)?;
+                let default_parameters = extract_default_parameters(function, has_gd_self)?;
                 let external_attributes = function.attributes.clone();
 
                 // Signatures are the same thing without body.
@@ -158,10 +199,69 @@ fn process_godot_fns(
                     None
                 };
 
+                // A trailing `&[&Variant]` parameter receives any additional arguments.
+                let is_variadic = remove_varargs_parameter(&mut signature);
+                if is_variadic {
+                    let unsupported = if is_virtual {
+                        Some("variadic functions cannot be virtual")
+                    } else if !default_parameters.is_empty() {
+                        Some("variadic functions cannot have default parameters")
+                    } else if rpc_attr.is_some() {
+                        Some("#[rpc] is not supported for variadic functions")
+                    } else {
+                        None
+                    };
+
+                    if let Some(msg) = unsupported {
+                        return bail_attr(attr.attr_name, msg, function);
+                    }
+                }
+
                 // Clone might not strictly be necessary, but the 2 other callers of into_signature_info() are better off with pass-by-value.
                 let signature_info =
                     into_signature_info(signature.clone(), class_name, gd_self_parameter.is_some());
 
+                // `Result` return types are reported to Godot as errors, returning a fallback value.
+                let result_fallback = if is_result_type(&signature_info.ret_type) {
+                    if is_virtual {
+                        return bail_attr(
+                            attr.attr_name,
+                            "#[func(virtual)] does not support `Result` return types",
+                            function,
+                        );
+                    }
+
+                    Some(
+                        error_fallback
+                            .unwrap_or_else(|| quote! { ::godot::builtin::Variant::nil() }),
+                    )
+                } else if error_fallback.is_some() {
+                    return bail_attr(
+                        attr.attr_name,
+                        "attribute key `error_fallback` requires a `Result` return type",
+                        function,
+                    );
+                } else {
+                    None
+                };
+
+                if let Some(rpc_attr) = rpc_attr {
+                    if signature_info.receiver_type == ReceiverType::Static {
+                        return bail_attr(
+                            attr.attr_name,
+                            "#[rpc] requires a method with a `self` receiver or `gd_self`",
+                            function,
+                        );
+                    }
+
+                    rpc_definitions.push(RpcDefinition {
+                        signature_info: signature_info.clone(),
+                        godot_name: rename.clone().unwrap_or_else(|| function.name.to_string()),
+                        vis_marker: function.vis_marker.clone(),
+                        rpc_attr,
+                    });
+                }
+
                 // For virtual methods, rename/mangle existing user method and create a new method with the original name,
                 // which performs a dynamic dispatch.
                 if is_virtual {
@@ -182,6 +282,9 @@ fn process_godot_fns(
                     rename,
                     is_script_virtual: is_virtual,
                     has_gd_self,
+                    default_parameters,
+                    is_variadic,
+                    result_fallback,
                 });
             }
             ItemAttrType::Signal(ref _attr_val) => {
@@ -220,7 +323,7 @@ fn process_godot_fns(
         impl_block.body_items.push(member);
     }
 
-    Ok((func_definitions, signal_definitions))
+    Ok((func_definitions, signal_definitions, rpc_definitions))
 }
 
 fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefinition>> {
@@ -368,6 +471,9 @@ where
                 // #[func(gd_self)]
                 let has_gd_self = parser.handle_alone("gd_self")?;
 
+                // #[func(error_fallback = expr)]
+                let error_fallback = parser.handle_expr("error_fallback")?;
+
                 parser.finish()?;
 
                 ItemAttr {
@@ -377,15 +483,13 @@ where
                         rename,
                         is_virtual,
                         has_gd_self,
+                        error_fallback,
                     },
                 }
             }
 
             // #[signal]
             name if name == "signal" => {
-                // TODO once parameters are supported, this should probably be moved to the struct definition
-                // E.g. a zero-sized type Signal<(i32, String)> with a provided emit(i32, String) method
-                // This could even be made public (callable on the struct obj itself)
                 ItemAttr {
                     attr_name: attr_name.clone(),
                     index,
@@ -421,3 +525,10 @@ where
 fn bail_attr<R>(attr_name: Ident, msg: &str, method: &venial::Function) -> ParseResult<R> {
     bail!(&method.name, "#[{}]: {}", attr_name, msg)
 }
+
+fn has_rpc_attribute(function: &venial::Function) -> bool {
+    function.attributes.iter().any(|attr| {
+        attr.get_single_path_segment()
+            .map_or(false, |name| name == "rpc")
+    })
+}
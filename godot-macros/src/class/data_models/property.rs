@@ -49,6 +49,8 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             ty: field_type,
             var,
             export,
+            group,
+            subgroup,
             ..
         } = field;
 
@@ -75,15 +77,16 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             setter,
             hint,
             mut usage_flags,
+            ..
         } = var;
 
         let mut export_hint = None;
 
         if let Some(export) = export {
-            export_hint = export.to_export_hint();
+            export_hint = export.to_export_hint(field_type);
 
             if usage_flags.is_inferred() {
-                usage_flags = UsageFlags::InferredExport;
+                usage_flags = export.usage_flags();
             }
         }
 
@@ -159,6 +162,11 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             &mut export_tokens,
         );
 
+        // Godot assigns each property to the group registered most recently before it.
+        for group in [group, subgroup].into_iter().flatten() {
+            export_tokens.push(group.to_registration(&class_name_obj));
+        }
+
         export_tokens.push(quote! {
             use ::godot::sys::GodotFfi;
 
@@ -0,0 +1,229 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::class::SignatureInfo;
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+
+/// A `#[func]` which is additionally annotated with `#[rpc]`.
+pub struct RpcDefinition {
+    pub signature_info: SignatureInfo,
+
+    /// Name under which the method is registered in Godot.
+    pub godot_name: String,
+
+    /// Visibility of the user's method, reused for the generated helpers.
+    pub vis_marker: Option<venial::VisMarker>,
+
+    pub rpc_attr: RpcAttr,
+}
+
+/// Settings parsed from `#[rpc(...)]`.
+pub struct RpcAttr {
+    pub rpc_mode: RpcMode,
+    pub transfer_mode: TransferMode,
+    pub call_local: bool,
+    pub channel: u32,
+}
+
+pub enum RpcMode {
+    AnyPeer,
+    Authority,
+}
+
+pub enum TransferMode {
+    Reliable,
+    Unreliable,
+    UnreliableOrdered,
+}
+
+/// Parses `#[rpc(...)]` on a function, if present. The attribute is removed from `function`.
+///
+/// Keys mirror GDScript's `@rpc` annotation:
+/// - `any_peer` | `authority` (default)
+/// - `reliable` | `unreliable` (default) | `unreliable_ordered`
+/// - `call_local` | `call_remote` (default)
+/// - `channel = N` (default 0)
+pub fn parse_rpc_attribute(function: &mut venial::Function) -> ParseResult<Option<RpcAttr>> {
+    let Some(mut parser) = KvParser::parse(&function.attributes, "rpc")? else {
+        return Ok(None);
+    };
+
+    let rpc_mode = match exclusive_keys(&mut parser, &["any_peer", "authority"])? {
+        Some("any_peer") => RpcMode::AnyPeer,
+        _ => RpcMode::Authority,
+    };
+
+    let transfer_mode = match exclusive_keys(
+        &mut parser,
+        &["reliable", "unreliable", "unreliable_ordered"],
+    )? {
+        Some("reliable") => TransferMode::Reliable,
+        Some("unreliable_ordered") => TransferMode::UnreliableOrdered,
+        _ => TransferMode::Unreliable,
+    };
+
+    let call_local = matches!(
+        exclusive_keys(&mut parser, &["call_local", "call_remote"])?,
+        Some("call_local")
+    );
+
+    let channel = match parser.handle_usize("channel")? {
+        Some(channel) => match u32::try_from(channel) {
+            Ok(channel) => channel,
+            Err(_) => return bail!(&function.name, "#[rpc]: `channel` is out of range"),
+        },
+        None => 0,
+    };
+
+    parser.finish()?;
+
+    function.attributes.retain(|attr| {
+        attr.get_single_path_segment()
+            .map_or(true, |name| name != "rpc")
+    });
+
+    Ok(Some(RpcAttr {
+        rpc_mode,
+        transfer_mode,
+        call_local,
+        channel,
+    }))
+}
+
+/// Body of `ImplementsGodotApi::__register_rpcs()`, or `None` if the class has no RPCs.
+pub fn make_rpc_registrations_fn(rpcs: &[RpcDefinition]) -> Option<TokenStream> {
+    if rpcs.is_empty() {
+        return None;
+    }
+
+    let configs = rpcs.iter().map(|rpc| {
+        let godot_name = &rpc.godot_name;
+        let RpcAttr {
+            rpc_mode,
+            transfer_mode,
+            call_local,
+            channel,
+        } = &rpc.rpc_attr;
+
+        let rpc_mode = match rpc_mode {
+            RpcMode::AnyPeer => quote! { ANY_PEER },
+            RpcMode::Authority => quote! { AUTHORITY },
+        };
+        let transfer_mode = match transfer_mode {
+            TransferMode::Reliable => quote! { RELIABLE },
+            TransferMode::Unreliable => quote! { UNRELIABLE },
+            TransferMode::UnreliableOrdered => quote! { UNRELIABLE_ORDERED },
+        };
+
+        quote! {
+            ::godot::register::RpcConfig {
+                rpc_mode: ::godot::engine::multiplayer_api::RpcMode::#rpc_mode,
+                transfer_mode: ::godot::engine::multiplayer_peer::TransferMode::#transfer_mode,
+                call_local: #call_local,
+                channel: #channel,
+            }
+            .configure_node(&mut node, #godot_name);
+        }
+    });
+
+    Some(quote! {
+        fn __register_rpcs(object: &mut dyn ::std::any::Any) {
+            let object = object
+                .downcast_mut::<::godot::obj::Gd<Self>>()
+                .expect("bad type erasure when registering RPCs");
+
+            // Fails to compile if the class does not inherit Node, which is the only class supporting RPCs.
+            let mut node = object.clone().upcast::<::godot::engine::Node>();
+
+            #( #configs )*
+        }
+    })
+}
+
+/// Generates `rpc_{method}()` and `rpc_id_{method}()` helpers, which invoke the RPC with type-checked arguments.
+pub fn make_rpc_helpers(class_name: &Ident, rpcs: &[RpcDefinition]) -> TokenStream {
+    if rpcs.is_empty() {
+        return TokenStream::new();
+    }
+
+    let helpers = rpcs.iter().map(|rpc| {
+        let method_name = &rpc.signature_info.method_name;
+        let godot_name = &rpc.godot_name;
+        let vis_marker = &rpc.vis_marker;
+        let param_idents = &rpc.signature_info.param_idents;
+        let param_types = &rpc.signature_info.param_types;
+
+        let rpc_name = format_ident!("rpc_{method_name}");
+        let rpc_id_name = format_ident!("rpc_id_{method_name}");
+        // Named to avoid clashing with a `peer_id` parameter, which is common for RPCs.
+        let peer_id = Ident::new("target_peer_id", Span::call_site());
+
+        let rpc_doc = format!(
+            "Calls `{godot_name}` on remote peers via [`Node::rpc()`][::godot::engine::Node::rpc], \
+            according to its `#[rpc]` configuration."
+        );
+        let rpc_id_doc = format!(
+            "Calls `{godot_name}` on the peer with given ID via [`Node::rpc_id()`][::godot::engine::Node::rpc_id], \
+            according to its `#[rpc]` configuration."
+        );
+
+        let args = quote! {
+            [ #( ::godot::builtin::meta::ToGodot::to_variant(&#param_idents), )* ]
+        };
+
+        quote! {
+            #[doc = #rpc_doc]
+            #[allow(clippy::too_many_arguments)]
+            #vis_marker fn #rpc_name(&mut self, #( #param_idents: #param_types ),*) -> ::godot::engine::global::Error {
+                let args = #args;
+                ::godot::obj::WithBaseField::base_mut(self)
+                    .rpc(::godot::builtin::StringName::from(#godot_name), &args)
+            }
+
+            #[doc = #rpc_id_doc]
+            #[allow(clippy::too_many_arguments)]
+            #vis_marker fn #rpc_id_name(&mut self, #peer_id: i64, #( #param_idents: #param_types ),*) -> ::godot::engine::global::Error {
+                let args = #args;
+                ::godot::obj::WithBaseField::base_mut(self)
+                    .rpc_id(#peer_id, ::godot::builtin::StringName::from(#godot_name), &args)
+            }
+        }
+    });
+
+    quote! {
+        impl #class_name {
+            #( #helpers )*
+        }
+    }
+}
+
+/// Handles a group of mutually exclusive keys without value. Returns the key that was provided, if any.
+fn exclusive_keys(
+    parser: &mut KvParser,
+    keys: &[&'static str],
+) -> ParseResult<Option<&'static str>> {
+    let mut found: Option<&'static str> = None;
+
+    for &key in keys {
+        if let Some(span) = parser.handle_alone_with_span(key)? {
+            if let Some(previous) = found {
+                return bail!(
+                    span,
+                    "#[rpc] attribute keys `{previous}` and `{key}` are mutually exclusive"
+                );
+            }
+
+            found = Some(key);
+        }
+    }
+
+    Ok(found)
+}
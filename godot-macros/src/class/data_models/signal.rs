@@ -6,8 +6,8 @@
  */
 
 use crate::util;
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
 
 /// Holds information known from a signal's definition
 pub struct SignalDefinition {
@@ -19,7 +19,7 @@ pub struct SignalDefinition {
 }
 
 pub fn make_signal_registrations(
-    signals: Vec<SignalDefinition>,
+    signals: &[SignalDefinition],
     class_name_obj: &TokenStream,
 ) -> Vec<TokenStream> {
     let mut signal_registrations = Vec::new();
@@ -89,3 +89,151 @@ pub fn make_signal_registrations(
     }
     signal_registrations
 }
+
+/// Generates the type-safe signal collection, i.e. `self.signals().my_signal().emit(...)`.
+///
+/// Returns `None` if the class declares no signals.
+pub fn make_signal_collection(
+    class_name: &Ident,
+    signals: &[SignalDefinition],
+) -> Option<TokenStream> {
+    if signals.is_empty() {
+        return None;
+    }
+
+    let collection_name = format_ident!("__godot_Signals_{}", class_name);
+    let mut accessors = Vec::new();
+    let mut signal_types = Vec::new();
+
+    for signal in signals.iter() {
+        let SignalDefinition {
+            signature,
+            external_attributes,
+        } = signal;
+
+        let mut param_types: Vec<venial::TypeExpr> = Vec::new();
+        let mut param_names: Vec<Ident> = Vec::new();
+
+        for param in signature.params.inner.iter() {
+            if let venial::FnParam::Typed(param) = &param.0 {
+                param_types.push(param.ty.clone());
+                param_names.push(param.name.clone());
+            }
+        }
+
+        let signal_cfg_attrs: Vec<&venial::Attribute> =
+            util::extract_cfg_attrs(external_attributes)
+                .into_iter()
+                .collect();
+
+        let signal_name = &signature.name;
+        let signal_name_str = signal_name.to_string();
+        let signal_type = format_ident!("__godot_Signal_{}_{}", class_name, signal_name);
+        let signature_tuple = util::make_signature_tuple_type(&quote! { () }, &param_types);
+        let params_tuple = quote! { (#( #param_types, )*) };
+
+        let doc = format!("Type-safe signal `{signal_name_str}`.");
+
+        // Typed connections rely on Callable::from_fn(), available since Godot 4.2.
+        let connect_method = if cfg!(since_api = "4.2") {
+            quote! {
+                /// Connects a function or closure, which receives the signal arguments with their declared types.
+                pub fn connect<F>(&mut self, function: F)
+                where
+                    F: ::godot::builtin::SignalReceiver<#params_tuple> + Send + Sync,
+                {
+                    self.typed.connect(function)
+                }
+
+                /// Connects a function or closure; the connection is removed when the returned guard is dropped.
+                pub fn connect_guarded<F>(&mut self, function: F) -> ::godot::builtin::ConnectionGuard
+                where
+                    F: ::godot::builtin::SignalReceiver<#params_tuple> + Send + Sync,
+                {
+                    self.typed.connect_guarded(function)
+                }
+
+                /// Like `connect_guarded()`, but the connection is additionally removed once `owner` is freed.
+                pub fn connect_tracked<F>(
+                    &mut self,
+                    owner: ::godot::obj::InstanceId,
+                    function: F,
+                ) -> ::godot::builtin::ConnectionGuard
+                where
+                    F: ::godot::builtin::SignalReceiver<#params_tuple> + Send + Sync,
+                {
+                    self.typed.connect_tracked(owner, function)
+                }
+
+                /// Returns a future that resolves with the arguments of the next emission.
+                pub fn to_future(&self) -> ::godot::task::SignalFuture<#params_tuple> {
+                    self.typed.to_future()
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        accessors.push(quote! {
+            #(#signal_cfg_attrs)*
+            #[doc = #doc]
+            pub fn #signal_name(&mut self) -> #signal_type<'_> {
+                #signal_type {
+                    typed: ::godot::builtin::TypedSignal::new(self.object.reborrow(), #signal_name_str),
+                }
+            }
+        });
+
+        signal_types.push(quote! {
+            #(#signal_cfg_attrs)*
+            #[doc = #doc]
+            #[allow(non_camel_case_types)]
+            pub struct #signal_type<'c> {
+                typed: ::godot::builtin::TypedSignal<'c, #class_name, #signature_tuple>,
+            }
+
+            #(#signal_cfg_attrs)*
+            impl #signal_type<'_> {
+                /// Emits the signal with the given arguments.
+                pub fn emit(&mut self, #( #param_names: #param_types ),*) {
+                    self.typed.emit_tuple((#( #param_names, )*))
+                }
+
+                #connect_method
+
+                /// Returns the untyped `Signal`.
+                pub fn to_untyped(&self) -> ::godot::builtin::Signal {
+                    self.typed.to_untyped()
+                }
+            }
+        });
+    }
+
+    let collection_doc = format!("Type-safe signals declared by `{class_name}`.");
+
+    let code = quote! {
+        #[doc = #collection_doc]
+        #[allow(non_camel_case_types)]
+        pub struct #collection_name<'c> {
+            object: ::godot::builtin::SignalObject<'c, #class_name>,
+        }
+
+        impl #collection_name<'_> {
+            #( #accessors )*
+        }
+
+        #( #signal_types )*
+
+        impl ::godot::obj::WithSignals for #class_name {
+            type SignalCollection<'c> = #collection_name<'c>;
+
+            fn __signals_from_object(
+                object: ::godot::builtin::SignalObject<'_, Self>,
+            ) -> Self::SignalCollection<'_> {
+                #collection_name { object }
+            }
+        }
+    };
+
+    Some(code)
+}
@@ -9,8 +9,8 @@ use proc_macro2::{Ident, Punct, TokenStream};
 use quote::{format_ident, quote};
 
 use crate::class::{
-    make_property_impl, make_virtual_callback, BeforeKind, Field, FieldExport, FieldVar, Fields,
-    SignatureInfo,
+    make_property_impl, make_virtual_callback, BeforeKind, Field, FieldExport, FieldGroup,
+    FieldVar, Fields, GroupKind, SignatureInfo,
 };
 use crate::util::{bail, ident, path_ends_with_complex, require_api_version, KvParser};
 use crate::{util, ParseResult};
@@ -63,7 +63,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         TokenStream::new()
     };
 
-    let (user_class_impl, has_default_virtual) =
+    let (user_class_impl, has_default_virtual, has_default_property_revert) =
         make_user_class_impl(class_name, struct_cfg.is_tool, &fields.all_fields);
 
     let mut init_expecter = TokenStream::new();
@@ -103,6 +103,16 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         quote! { None }
     };
 
+    let (default_property_can_revert_fn, default_property_get_revert_fn) =
+        if has_default_property_revert {
+            (
+                quote! { Some(#prv::callbacks::default_property_can_revert::<#class_name>) },
+                quote! { Some(#prv::callbacks::default_property_get_revert::<#class_name>) },
+            )
+        } else {
+            (quote! { None }, quote! { None })
+        };
+
     let is_tool = struct_cfg.is_tool;
 
     Ok(quote! {
@@ -137,6 +147,8 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                 },
                 free_fn: #prv::callbacks::free::<#class_name>,
                 default_get_virtual_fn: #default_get_virtual_fn,
+                default_property_can_revert_fn: #default_property_can_revert_fn,
+                default_property_get_revert_fn: #default_property_get_revert_fn,
                 is_tool: #is_tool,
                 is_editor_plugin: #is_editor_plugin,
                 is_hidden: #is_hidden,
@@ -224,7 +236,7 @@ fn make_user_class_impl(
     class_name: &Ident,
     is_tool: bool,
     all_fields: &[Field],
-) -> (TokenStream, bool) {
+) -> (TokenStream, bool, bool) {
     let onready_field_inits = all_fields
         .iter()
         .filter(|&field| field.is_onready)
@@ -257,6 +269,8 @@ fn make_user_class_impl(
         None
     };
 
+    let default_property_revert_fn = make_default_property_revert_fn(all_fields);
+
     let user_class_impl = quote! {
         impl ::godot::obj::UserClass for #class_name {
             fn __config() -> ::godot::private::ClassConfig {
@@ -270,10 +284,49 @@ fn make_user_class_impl(
             }
 
             #default_virtual_fn
+            #default_property_revert_fn
         }
     };
 
-    (user_class_impl, default_virtual_fn.is_some())
+    (
+        user_class_impl,
+        default_virtual_fn.is_some(),
+        default_property_revert_fn.is_some(),
+    )
+}
+
+/// Maps properties to their `#[init(default = ...)]` values, which the editor offers to revert to.
+fn make_default_property_revert_fn(all_fields: &[Field]) -> Option<TokenStream> {
+    let match_arms: Vec<TokenStream> = all_fields
+        .iter()
+        .filter(|field| field.var.is_some() || field.export.is_some())
+        .filter_map(|field| {
+            let default = field.default.as_ref()?;
+            let field_name = field.name.to_string();
+            let field_type = &field.ty;
+
+            Some(quote! {
+                #field_name => {
+                    let value: #field_type = #default;
+                    let value = <#field_type as ::godot::register::property::Var>::get_property(&value);
+                    Some(::godot::builtin::meta::ToGodot::to_variant(&value))
+                }
+            })
+        })
+        .collect();
+
+    if match_arms.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        fn __default_property_revert(property: &::godot::builtin::StringName) -> Option<::godot::builtin::Variant> {
+            match property.to_string().as_str() {
+                #( #match_arms )*
+                _ => None,
+            }
+        }
+    })
 }
 
 /// Returns the name of the base and the default mode
@@ -417,6 +470,31 @@ fn parse_fields(class: &venial::Struct, init_strategy: InitStrategy) -> ParseRes
             parser.finish()?;
         }
 
+        // #[export_group]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_group")? {
+            let group = FieldGroup::new_from_kv(&mut parser, GroupKind::Group)?;
+            field.group = Some(group);
+            parser.finish()?;
+        }
+
+        // #[export_subgroup]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_subgroup")? {
+            let subgroup = FieldGroup::new_from_kv(&mut parser, GroupKind::Subgroup)?;
+            field.subgroup = Some(subgroup);
+            parser.finish()?;
+        }
+
+        // Groups are registered along with the property, so they need one.
+        if (field.group.is_some() || field.subgroup.is_some())
+            && field.var.is_none()
+            && field.export.is_none()
+        {
+            return bail!(
+                named_field,
+                "#[export_group] and #[export_subgroup] require the field to have #[export] or #[var]"
+            );
+        }
+
         // #[hint] to override type inference (must be at the end).
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "hint")? {
             if let Some(override_base) = handle_opposite_keys(&mut parser, "base", "hint")? {
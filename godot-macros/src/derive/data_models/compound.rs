@@ -0,0 +1,232 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Fields of a struct or enum variant which is converted field by field, to a `Dictionary` or an `Array`.
+pub struct CompoundFields {
+    pub kind: FieldsKind,
+    pub fields: Vec<CompoundField>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum FieldsKind {
+    Named,
+    Tuple,
+    Unit,
+}
+
+impl CompoundFields {
+    /// Parses the fields of a struct or enum variant, including their `#[godot(...)]` attributes.
+    pub fn parse_fields(fields: &venial::Fields) -> ParseResult<Self> {
+        let (kind, fields) = match fields {
+            venial::Fields::Unit => (FieldsKind::Unit, vec![]),
+            venial::Fields::Tuple(fields) => {
+                let fields = fields
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (field, _punct))| {
+                        CompoundField::parse(None, index, &field.ty, &field.attributes)
+                    })
+                    .collect::<ParseResult<Vec<_>>>()?;
+
+                (FieldsKind::Tuple, fields)
+            }
+            venial::Fields::Named(fields) => {
+                let fields = fields
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (field, _punct))| {
+                        CompoundField::parse(
+                            Some(field.name.clone()),
+                            index,
+                            &field.ty,
+                            &field.attributes,
+                        )
+                    })
+                    .collect::<ParseResult<Vec<_>>>()?;
+
+                (FieldsKind::Named, fields)
+            }
+        };
+
+        Ok(Self { kind, fields })
+    }
+
+    /// Fields that take part in the conversion, i.e. all except `#[godot(skip)]` ones.
+    pub fn converted(&self) -> impl Iterator<Item = &CompoundField> {
+        self.fields.iter().filter(|field| !field.skip)
+    }
+
+    /// Constructor expression for `path` (`Self` or an enum variant), given one value expression per field in declaration order.
+    pub fn make_constructor(&self, path: &TokenStream, values: &[TokenStream]) -> TokenStream {
+        match self.kind {
+            FieldsKind::Unit => quote! { #path },
+            FieldsKind::Tuple => quote! { #path ( #( #values, )* ) },
+            FieldsKind::Named => {
+                let names = self.fields.iter().map(|field| &field.name);
+                quote! { #path { #( #names: #values, )* } }
+            }
+        }
+    }
+
+    /// Pattern binding all fields of `path` to the field's `binding()` ident.
+    pub fn make_pattern(&self, path: &TokenStream) -> TokenStream {
+        let bindings = self.fields.iter().map(CompoundField::binding);
+
+        match self.kind {
+            FieldsKind::Unit => quote! { #path },
+            FieldsKind::Tuple => quote! { #path ( #( #bindings, )* ) },
+            FieldsKind::Named => {
+                let names = self.fields.iter().map(|field| &field.name);
+                quote! { #path { #( #names: #bindings, )* } }
+            }
+        }
+    }
+}
+
+/// A single field of [`CompoundFields`].
+pub struct CompoundField {
+    /// Name of the field, or its index for tuple fields.
+    pub name: TokenStream,
+
+    /// Position among all fields, including skipped ones.
+    pub index: usize,
+
+    pub ty: venial::TypeExpr,
+
+    /// Dictionary key for named fields: the field name or `#[godot(rename = ...)]`. For tuple fields, the index as string.
+    pub key: String,
+
+    /// Value used if the field is absent (`#[godot(default)]` or `#[godot(default = expr)]`), or skipped.
+    pub default: Option<TokenStream>,
+
+    /// `#[godot(skip)]`
+    pub skip: bool,
+}
+
+impl CompoundField {
+    fn parse(
+        name: Option<Ident>,
+        index: usize,
+        ty: &venial::TypeExpr,
+        attributes: &[venial::Attribute],
+    ) -> ParseResult<Self> {
+        let mut key = name
+            .as_ref()
+            .map_or_else(|| index.to_string(), Ident::to_string);
+        let mut default = None;
+        let mut skip = false;
+
+        if let Some(mut parser) = KvParser::parse(attributes, "godot")? {
+            if let Some((rename_key, rename)) = parser.handle_ident_or_string("rename")? {
+                if name.is_none() {
+                    return bail!(
+                        rename_key,
+                        "`#[godot(rename)]` is only supported on named fields"
+                    );
+                }
+
+                key = rename;
+            }
+
+            default = match parser.handle_any("default") {
+                None => None,
+                Some(None) => Some(quote! { ::std::default::Default::default() }),
+                Some(Some(value)) => Some(value.expr()?),
+            };
+
+            skip = parser.handle_alone("skip")?;
+            parser.finish()?;
+        }
+
+        // Skipped fields are always default-constructed.
+        if skip && default.is_none() {
+            default = Some(quote! { ::std::default::Default::default() });
+        }
+
+        let name = match name {
+            Some(name) => quote! { #name },
+            None => {
+                let index = Literal::usize_unsuffixed(index);
+                quote! { #index }
+            }
+        };
+
+        Ok(Self {
+            name,
+            index,
+            ty: ty.clone(),
+            key,
+            default,
+            skip,
+        })
+    }
+
+    /// Identifier to which the field is bound in patterns.
+    pub fn binding(&self) -> Ident {
+        quote::format_ident!("__field_{}", self.index)
+    }
+}
+
+/// Dictionary key holding the variant name of an enum converted to a tagged `Dictionary`.
+pub const TAG_KEY: &str = "variant";
+
+/// A variant of an enum converted to a tagged `Dictionary`.
+pub struct DataVariant {
+    pub name: Ident,
+
+    /// Value of the tag: the variant name or `#[godot(rename = ...)]`.
+    pub key: String,
+
+    pub fields: CompoundFields,
+}
+
+impl DataVariant {
+    /// Parses all variants of an enum, which may carry data.
+    pub fn parse_enum(enum_: &venial::Enum) -> ParseResult<Vec<Self>> {
+        enum_
+            .variants
+            .items()
+            .map(|variant| {
+                let mut key = variant.name.to_string();
+
+                if let Some(mut parser) = KvParser::parse(&variant.attributes, "godot")? {
+                    if let Some((_, rename)) = parser.handle_ident_or_string("rename")? {
+                        key = rename;
+                    }
+
+                    parser.finish()?;
+                }
+
+                let fields = CompoundFields::parse_fields(&variant.fields)?;
+
+                // The tag would be overwritten by the field's entry.
+                if fields.kind == FieldsKind::Named {
+                    if let Some(field) = fields.converted().find(|field| field.key == TAG_KEY) {
+                        return bail!(
+                            &field.name,
+                            "key `{TAG_KEY}` is reserved for the variant tag; use `#[godot(rename = ...)]` on the field"
+                        );
+                    }
+                }
+
+                Ok(Self {
+                    name: variant.name.clone(),
+                    key,
+                    fields,
+                })
+            })
+            .collect()
+    }
+}
@@ -17,6 +17,10 @@ pub enum GodotAttribute {
     Transparent { span: Span },
     /// `#[godot(via = via_type)]`
     Via { span: Span, via_type: ViaType },
+    /// `#[godot(dictionary)]`
+    Dictionary { span: Span },
+    /// `#[godot(array)]`
+    Array { span: Span },
 }
 
 impl GodotAttribute {
@@ -35,6 +39,14 @@ impl GodotAttribute {
             return Ok(Self::Transparent { span });
         }
 
+        if parser.handle_alone("dictionary")? {
+            return Ok(Self::Dictionary { span });
+        }
+
+        if parser.handle_alone("array")? {
+            return Ok(Self::Array { span });
+        }
+
         if let Some(via_type) = parser.handle_ident("via")? {
             return Ok(Self::Via {
                 span,
@@ -44,7 +56,7 @@ impl GodotAttribute {
 
         bail!(
             span,
-            "expected either `#[godot(transparent)]` or `#[godot(via = <via_type>)]`"
+            "expected one of `#[godot(transparent)]`, `#[godot(via = <via_type>)]`, `#[godot(dictionary)]` or `#[godot(array)]`"
         )
     }
 
@@ -55,6 +67,8 @@ impl GodotAttribute {
         match self {
             GodotAttribute::Transparent { span } => *span,
             GodotAttribute::Via { span, .. } => *span,
+            GodotAttribute::Dictionary { span } => *span,
+            GodotAttribute::Array { span } => *span,
         }
     }
 }
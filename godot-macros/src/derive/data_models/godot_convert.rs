@@ -6,12 +6,13 @@
  */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 
 use crate::util::bail;
 use crate::ParseResult;
 
 use super::c_style_enum::CStyleEnum;
+use super::compound::{CompoundFields, DataVariant, FieldsKind};
 use super::godot_attribute::{GodotAttribute, ViaType};
 use super::newtype::NewtypeStruct;
 
@@ -70,6 +71,12 @@ pub enum ConvertType {
     NewType { field: NewtypeStruct },
     /// Deriving for an enum.
     Enum { variants: CStyleEnum, via: ViaType },
+    /// Deriving for a struct with named fields, converted to a `Dictionary`.
+    Dictionary { fields: CompoundFields },
+    /// Deriving for a tuple struct, converted to an `Array`.
+    Array { fields: CompoundFields },
+    /// Deriving for an enum whose variants may carry data, converted to a tagged `Dictionary`.
+    DataEnum { variants: Vec<DataVariant> },
 }
 
 impl ConvertType {
@@ -77,28 +84,50 @@ impl ConvertType {
         let attribute = GodotAttribute::parse_attribute(&item)?;
 
         match &item {
-            venial::Item::Struct(struct_) => {
-                let GodotAttribute::Transparent { .. } = attribute else {
-                    return bail!(attribute.span(), "`GodotConvert` on structs only works with `#[godot(transparent)]` currently");
-                };
-
-                Ok(Self::NewType {
+            venial::Item::Struct(struct_) => match attribute {
+                GodotAttribute::Transparent { .. } => Ok(Self::NewType {
                     field: NewtypeStruct::parse_struct(struct_)?,
-                })
-            }
-            venial::Item::Enum(enum_) => {
-                let GodotAttribute::Via { via_type, .. } = attribute else {
-                    return bail!(
-                        attribute.span(),
-                        "`GodotConvert` on enums requires `#[godot(via = ...)]` currently"
-                    );
-                };
+                }),
+                GodotAttribute::Dictionary { span } => {
+                    let fields = CompoundFields::parse_fields(&struct_.fields)?;
+                    if fields.kind != FieldsKind::Named {
+                        return bail!(
+                            span,
+                            "`#[godot(dictionary)]` expects a struct with named fields; use `#[godot(array)]` for tuple structs"
+                        );
+                    }
+
+                    Ok(Self::Dictionary { fields })
+                }
+                GodotAttribute::Array { span } => {
+                    let fields = CompoundFields::parse_fields(&struct_.fields)?;
+                    if fields.kind != FieldsKind::Tuple {
+                        return bail!(
+                            span,
+                            "`#[godot(array)]` expects a tuple struct; use `#[godot(dictionary)]` for named fields"
+                        );
+                    }
 
-                Ok(Self::Enum {
+                    Ok(Self::Array { fields })
+                }
+                GodotAttribute::Via { span, .. } => bail!(
+                    span,
+                    "`GodotConvert` on structs requires `#[godot(transparent)]`, `#[godot(dictionary)]` or `#[godot(array)]`"
+                ),
+            },
+            venial::Item::Enum(enum_) => match attribute {
+                GodotAttribute::Via { via_type, .. } => Ok(Self::Enum {
                     variants: CStyleEnum::parse_enum(enum_)?,
                     via: via_type,
-                })
-            }
+                }),
+                GodotAttribute::Dictionary { .. } => Ok(Self::DataEnum {
+                    variants: DataVariant::parse_enum(enum_)?,
+                }),
+                other => bail!(
+                    other.span(),
+                    "`GodotConvert` on enums requires `#[godot(via = ...)]` or `#[godot(dictionary)]`"
+                ),
+            },
             _ => bail!(
                 item,
                 "`GodotConvert` only supports structs and enums currently"
@@ -111,6 +140,10 @@ impl ConvertType {
         match self {
             ConvertType::NewType { field } => field.ty.to_token_stream(),
             ConvertType::Enum { via, .. } => via.to_token_stream(),
+            ConvertType::Dictionary { .. } | ConvertType::DataEnum { .. } => {
+                quote! { ::godot::builtin::VariantDictionary }
+            }
+            ConvertType::Array { .. } => quote! { ::godot::builtin::VariantArray },
         }
     }
 }
@@ -8,15 +8,17 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::ParseResult;
+use crate::{util, ParseResult};
 
 use crate::derive::data_models::GodotConvert;
 
 /// Derives `Export` for the declaration.
 ///
-/// This currently just reuses the property hint from the `Var` implementation.
+/// This currently just reuses the property hint from the `Var` implementation. The hint is also used for the elements of exported
+/// arrays, via `TypeStringHint`.
 pub fn derive_export(item: venial::Item) -> ParseResult<TokenStream> {
     let GodotConvert { ty_name: name, .. } = GodotConvert::parse_declaration(item)?;
+    let variant_type = util::property_variant_type(&name);
 
     Ok(quote! {
         impl ::godot::register::property::Export for #name {
@@ -24,5 +26,14 @@ pub fn derive_export(item: venial::Item) -> ParseResult<TokenStream> {
                 <#name as ::godot::register::property::Var>::property_hint()
             }
         }
+
+        impl ::godot::register::property::TypeStringHint for #name {
+            fn type_string() -> String {
+                use ::godot::sys::GodotFfi as _;
+
+                <#name as ::godot::register::property::Export>::default_export_info()
+                    .to_element_type_string(#variant_type)
+            }
+        }
     })
 }
@@ -8,7 +8,11 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, CompoundFields, ConvertType, DataVariant, FieldsKind, GodotConvert, NewtypeStruct,
+    ViaType, TAG_KEY,
+};
+use crate::derive::derive_to_godot::make_dictionary_key;
 
 /// Creates a `FromGodot` impl for the given `GodotConvert`.
 ///
@@ -29,6 +33,9 @@ pub fn make_fromgodot(convert: &GodotConvert) -> TokenStream {
             variants,
             via: ViaType::Int { int_ident },
         } => make_fromgodot_for_int_enum(name, variants, int_ident),
+        ConvertType::Dictionary { fields } => make_fromgodot_for_dictionary_struct(name, fields),
+        ConvertType::Array { fields } => make_fromgodot_for_array_struct(name, fields),
+        ConvertType::DataEnum { variants } => make_fromgodot_for_data_enum(name, variants),
     }
 }
 
@@ -89,3 +96,154 @@ fn make_fromgodot_for_gstring_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStr
         }
     }
 }
+
+/// Derives `FromGodot` for structs with named fields, converted from a `Dictionary` keyed by field name.
+fn make_fromgodot_for_dictionary_struct(name: &Ident, fields: &CompoundFields) -> TokenStream {
+    let constructor = make_dictionary_constructor(quote! { #name }, fields, None);
+
+    quote! {
+        impl ::godot::builtin::meta::FromGodot for #name {
+            fn try_from_godot(via: ::godot::builtin::VariantDictionary) -> ::std::result::Result<Self, ::godot::builtin::meta::ConvertError> {
+                Ok(#constructor)
+            }
+        }
+    }
+}
+
+/// Derives `FromGodot` for tuple structs, converted from an `Array` with one element per field.
+///
+/// Missing trailing elements are allowed for fields with a default value.
+fn make_fromgodot_for_array_struct(name: &Ident, fields: &CompoundFields) -> TokenStream {
+    let converted_len = fields.converted().count();
+    let too_long_error = format!("too many elements for {name}, expected at most {converted_len}");
+
+    let mut position = 0;
+    let values: Vec<TokenStream> = fields
+        .fields
+        .iter()
+        .map(|field| {
+            let ty = &field.ty;
+
+            if field.skip {
+                return field.default.clone().expect("skipped fields have a default");
+            }
+
+            let index = position;
+            position += 1;
+
+            let missing = match &field.default {
+                Some(default) => default.clone(),
+                None => quote! {
+                    return Err(::godot::builtin::meta::ConvertError::with_error_value("missing element", via).__in_index(#index))
+                },
+            };
+
+            quote! {
+                match via.try_get(#index) {
+                    Some(value) => value.try_to::<#ty>().map_err(|err| err.__in_index(#index))?,
+                    None => #missing,
+                }
+            }
+        })
+        .collect();
+
+    let constructor = fields.make_constructor(&quote! { #name }, &values);
+
+    quote! {
+        impl ::godot::builtin::meta::FromGodot for #name {
+            fn try_from_godot(via: ::godot::builtin::VariantArray) -> ::std::result::Result<Self, ::godot::builtin::meta::ConvertError> {
+                if via.len() > #converted_len {
+                    return Err(::godot::builtin::meta::ConvertError::with_error_value(#too_long_error, via));
+                }
+
+                Ok(#constructor)
+            }
+        }
+    }
+}
+
+/// Derives `FromGodot` for enums with data, converted from a `Dictionary` with the variant name under the `"variant"` key.
+fn make_fromgodot_for_data_enum(name: &Ident, variants: &[DataVariant]) -> TokenStream {
+    let arms = variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        let variant_key = &variant.key;
+        let constructor = make_dictionary_constructor(
+            quote! { #name::#variant_name },
+            &variant.fields,
+            Some(variant_key),
+        );
+
+        quote! {
+            #variant_key => Ok(#constructor),
+        }
+    });
+
+    let bad_variant_error = format!("invalid {name} variant");
+    let tag_key = TAG_KEY;
+
+    quote! {
+        impl ::godot::builtin::meta::FromGodot for #name {
+            fn try_from_godot(via: ::godot::builtin::VariantDictionary) -> ::std::result::Result<Self, ::godot::builtin::meta::ConvertError> {
+                let tag = match via.get(#tag_key) {
+                    Some(tag) => tag.try_to::<::godot::builtin::GString>().map_err(|err| err.__in_field(#tag_key))?,
+                    None => return Err(::godot::builtin::meta::ConvertError::with_error_value("missing field", via).__in_field(#tag_key)),
+                };
+
+                match tag.to_string().as_str() {
+                    #( #arms )*
+                    // Pass `via` and not `other`, to retain the other fields in the error.
+                    _ => Err(::godot::builtin::meta::ConvertError::with_error_value(#bad_variant_error, via).__in_field(#tag_key)),
+                }
+            }
+        }
+    }
+}
+
+/// Constructs `path` from the fields in the dictionary `via`. Errors are located at the field, prefixed by `variant_key` for enums.
+fn make_dictionary_constructor(
+    path: TokenStream,
+    fields: &CompoundFields,
+    variant_key: Option<&String>,
+) -> TokenStream {
+    let in_variant = variant_key.map(|key| quote! { .__in_field(#key) });
+
+    let values: Vec<TokenStream> = fields
+        .fields
+        .iter()
+        .map(|field| {
+            let ty = &field.ty;
+
+            if field.skip {
+                return field.default.clone().expect("skipped fields have a default");
+            }
+
+            let key = make_dictionary_key(fields.kind, &field.key, field.index);
+            let in_field = match fields.kind {
+                FieldsKind::Tuple => {
+                    let index = field.index;
+                    quote! { .__in_index(#index) }
+                }
+                _ => {
+                    let field_key = &field.key;
+                    quote! { .__in_field(#field_key) }
+                }
+            };
+
+            let missing = match &field.default {
+                Some(default) => default.clone(),
+                None => quote! {
+                    return Err(::godot::builtin::meta::ConvertError::with_error_value("missing field", via) #in_field #in_variant)
+                },
+            };
+
+            quote! {
+                match via.get(#key) {
+                    Some(value) => value.try_to::<#ty>().map_err(|err| err #in_field #in_variant)?,
+                    None => #missing,
+                }
+            }
+        })
+        .collect();
+
+    fields.make_constructor(&path, &values)
+}
@@ -14,7 +14,8 @@ use crate::ParseResult;
 
 /// Derives `GodotConvert` for the given declaration.
 ///
-/// This also derives `FromGodot` and `ToGodot`.
+/// This also derives `FromGodot` and `ToGodot`, and implements `ArrayElement` so the type can be stored in typed arrays, as long as its
+/// `Via` type can be stored in them.
 pub fn derive_godot_convert(item: venial::Item) -> ParseResult<TokenStream> {
     let convert = GodotConvert::parse_declaration(item)?;
 
@@ -31,5 +32,14 @@ pub fn derive_godot_convert(item: venial::Item) -> ParseResult<TokenStream> {
 
         #to_godot_impl
         #from_godot_impl
+
+        // Higher-ranked bound: a plain bound on a concrete type would be a compile error if it doesn't hold (e.g. newtype over `Array<T>`),
+        // instead of just leaving the impl unusable.
+        impl ::godot::builtin::meta::ArrayElement for #name
+        where
+            for<'a> #via_type: ::godot::builtin::meta::ArrayElement,
+        {
+            const __FALLIBLE_FROM_VIA: bool = true;
+        }
     })
 }
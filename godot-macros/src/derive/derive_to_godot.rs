@@ -8,7 +8,10 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, CompoundFields, ConvertType, DataVariant, FieldsKind, GodotConvert, NewtypeStruct,
+    ViaType, TAG_KEY,
+};
 
 /// Creates a `ToGodot` impl for the given `GodotConvert`.
 ///
@@ -29,6 +32,9 @@ pub fn make_togodot(convert: &GodotConvert) -> TokenStream {
             variants,
             via: ViaType::Int { int_ident },
         } => make_togodot_for_int_enum(name, variants, int_ident),
+        ConvertType::Dictionary { fields } => make_togodot_for_dictionary_struct(name, fields),
+        ConvertType::Array { fields } => make_togodot_for_array_struct(name, fields),
+        ConvertType::DataEnum { variants } => make_togodot_for_data_enum(name, variants),
     }
 }
 
@@ -85,3 +91,96 @@ fn make_togodot_for_string_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStream
         }
     }
 }
+
+/// Derives `ToGodot` for structs with named fields, converted to a `Dictionary` keyed by field name.
+fn make_togodot_for_dictionary_struct(name: &Ident, fields: &CompoundFields) -> TokenStream {
+    let bindings = fields.make_pattern(&quote! { #name });
+    let inserts = make_dictionary_inserts(fields);
+
+    quote! {
+        impl ::godot::builtin::meta::ToGodot for #name {
+            fn to_godot(&self) -> ::godot::builtin::VariantDictionary {
+                let #bindings = self;
+                let mut dict = ::godot::builtin::VariantDictionary::new();
+                #inserts
+                dict
+            }
+        }
+    }
+}
+
+/// Derives `ToGodot` for tuple structs, converted to an `Array` with one element per field.
+fn make_togodot_for_array_struct(name: &Ident, fields: &CompoundFields) -> TokenStream {
+    let bindings = fields.make_pattern(&quote! { #name });
+    let elements = fields.converted().map(|field| field.binding());
+
+    quote! {
+        impl ::godot::builtin::meta::ToGodot for #name {
+            fn to_godot(&self) -> ::godot::builtin::VariantArray {
+                let #bindings = self;
+                let mut array = ::godot::builtin::VariantArray::new();
+                #(
+                    array.push(::godot::builtin::meta::ToGodot::to_variant(#elements));
+                )*
+                array
+            }
+        }
+    }
+}
+
+/// Derives `ToGodot` for enums with data, converted to a `Dictionary` with the variant name under the `"variant"` key.
+fn make_togodot_for_data_enum(name: &Ident, variants: &[DataVariant]) -> TokenStream {
+    let arms = variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        let variant_key = &variant.key;
+        let pattern = variant
+            .fields
+            .make_pattern(&quote! { #name::#variant_name });
+        let inserts = make_dictionary_inserts(&variant.fields);
+        let tag_key = TAG_KEY;
+
+        quote! {
+            #pattern => {
+                let mut dict = ::godot::builtin::VariantDictionary::new();
+                dict.set(#tag_key, #variant_key);
+                #inserts
+                dict
+            }
+        }
+    });
+
+    quote! {
+        impl ::godot::builtin::meta::ToGodot for #name {
+            fn to_godot(&self) -> ::godot::builtin::VariantDictionary {
+                match self {
+                    #( #arms )*
+                }
+            }
+        }
+    }
+}
+
+/// Inserts the bound fields into `dict`. Named fields are keyed by name, tuple fields by index.
+fn make_dictionary_inserts(fields: &CompoundFields) -> TokenStream {
+    let inserts = fields.converted().map(|field| {
+        let binding = field.binding();
+        let key = make_dictionary_key(fields.kind, &field.key, field.index);
+
+        quote! {
+            dict.set(#key, ::godot::builtin::meta::ToGodot::to_variant(#binding));
+        }
+    });
+
+    quote! { #( #inserts )* }
+}
+
+/// Key of a field in a dictionary: the field's name, or its index for tuple fields.
+pub(super) fn make_dictionary_key(kind: FieldsKind, key: &str, index: usize) -> TokenStream {
+    match kind {
+        FieldsKind::Tuple => {
+            let index = index as i64;
+            quote! { #index }
+        }
+        _ => quote! { #key },
+    }
+}
@@ -41,7 +41,8 @@ pub fn derive_var(item: venial::Item) -> ParseResult<TokenStream> {
 
 /// Make an appropriate property hint implementation.
 ///
-/// For newtype structs we just defer to the wrapped type. For enums we use `PropertyHint::ENUM` with an appropriate hint string.
+/// For newtype structs we just defer to the wrapped type, and types converted to dictionaries or arrays use the hint of `Dictionary` or
+/// `VariantArray`. For C-style enums we use `PropertyHint::ENUM` with an appropriate hint string.
 fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
     use super::data_models::ConvertType as Data;
     use super::data_models::ViaType;
@@ -53,6 +54,16 @@ fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
                 <#ty as ::godot::register::property::Var>::property_hint()
             }
         }
+        Data::Dictionary { .. } | Data::DataEnum { .. } => {
+            quote! {
+                <::godot::builtin::VariantDictionary as ::godot::register::property::Var>::property_hint()
+            }
+        }
+        Data::Array { .. } => {
+            quote! {
+                <::godot::builtin::VariantArray as ::godot::register::property::Var>::property_hint()
+            }
+        }
         Data::Enum { variants, via } => {
             let hint_string = match via {
                 ViaType::GString { .. } => variants.to_string_hint(),
@@ -90,6 +90,10 @@ use crate::util::ident;
 /// # }
 /// ```
 ///
+/// For `#[var]` and `#[export]` fields, the default value also becomes the value that the editor inspector can revert the property to.
+/// The expression is evaluated again each time Godot queries that value, so avoid expressions allocating manually-managed objects.
+/// To provide revert values yourself, override `property_get_revert()` in the class's `I*` interface trait.
+///
 /// You can also _disable_ construction from GDScript. This needs to be explicit via `#[class(no_init)]`.
 /// Simply omitting the `init`/`no_init` keys and not overriding your own constructor will cause a compile error.
 ///
@@ -212,6 +216,35 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// To sanitize or react to assignments without writing a custom setter, the generated setter can call hooks on your type:
+/// - `validate = fn_name` calls `fn_name(&self, value) -> value` before the field is assigned. It receives and returns the
+///   value in its `GodotConvert::Via` representation, and can be used to clamp or otherwise adjust it.
+/// - `on_change = fn_name` calls `fn_name(&mut self)` after the field has been assigned.
+/// - `notify_property_list_changed` calls `notify_property_list_changed()` on the base object after the assignment, so that
+///   the editor refreshes the inspector. This requires a `Base<T>` field.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[var(validate = clamp_health, on_change = health_changed)]
+///     health: i64,
+/// }
+///
+/// impl MyStruct {
+///     fn clamp_health(&self, value: i64) -> i64 {
+///         value.clamp(0, 100)
+///     }
+///
+///     fn health_changed(&mut self) {
+///         godot_print!("health is now {}", self.health);
+///     }
+/// }
+/// ```
+///
+/// These keys cannot be combined with a custom setter (`set = ...`); call the functions from the setter instead.
+///
 /// ## Property exports
 ///
 /// For exporting properties to the editor, you can use the `#[export]` attribute:
@@ -276,10 +309,36 @@ use crate::util::ident;
 ///     // @export_flags("A:1", "B:2", "AB:3")
 ///     #[export(flags = (A = 1, B = 2, AB = 3))]
 ///     flags: u32,
+///
+///     // @export_node_path("Node2D", "Sprite2D")
+///     #[export(node_path = (Node2D, Sprite2D))]
+///     target: NodePath,
+///
+///     // @export_color_no_alpha, also applies to array elements
+///     #[export(color_no_alpha)]
+///     palette: Array<Color>,
+///
+///     // @export_storage
+///     #[export(storage)]
+///     saved_but_hidden: i64,
+///
+///     // @export_custom(PROPERTY_HINT_ENUM, "Low,High", PROPERTY_USAGE_DEFAULT)
+///     #[export(custom = (hint = ENUM, hint_string = "Low,High", usage_flags = [DEFAULT]))]
+///     custom: i64,
+///
+///     // @export_range(0, 100) var scores: Array[int]
+///     #[export(element = (range = (0, 100)))]
+///     scores: Array<i64>,
 /// }
 ///
 /// ```
 ///
+/// `#[export(multiline)]` and `#[export(color_no_alpha)]` can be used on arrays of strings and colors as well, in which case they apply
+/// to each element. On arrays of other types, the default hint is used.
+/// Other hints can be applied to the elements of typed and packed arrays with `#[export(element = (...))]`, which accepts any of the
+/// above keys except `storage` and `usage_flags`.
+/// `#[export(flags_avoidance)]` requires Godot 4.1 or later.
+///
 /// Most values in expressions like `key = value`, can be an arbitrary expression that evaluates to the
 /// right value. Meaning you can use constants or variables, as well as any other rust syntax you'd like in
 /// the export attributes.
@@ -320,10 +379,42 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// ## Property groups
+///
+/// Exported properties can be grouped in the inspector with `#[export_group]` and `#[export_subgroup]`, the counterparts of
+/// GDScript's `@export_group` and `@export_subgroup`. The attribute is placed on the first field of the group; all following
+/// properties belong to it, until the next group starts. An optional `prefix` is stripped from the property names shown in the group.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct Enemy {
+///     #[export]
+///     name: GString,
+///
+///     // @export_group("Movement", "movement_")
+///     #[export_group(name = "Movement", prefix = "movement_")]
+///     #[export]
+///     movement_speed: f32,
+///
+///     // @export_subgroup("Jump")
+///     #[export_subgroup(name = "Jump")]
+///     #[export]
+///     movement_jump_height: f32,
+///
+///     // @export_group("")
+///     #[export_group(name = "")]
+///     #[export]
+///     loot_table: GString,
+/// }
+/// ```
+///
+/// An empty name ends the current group, so that the following properties are not grouped anymore.
+///
 /// # Signals
 ///
-/// The `#[signal]` attribute is quite limited at the moment. The functions it decorates (the signals) can accept parameters.
-/// It will be fundamentally reworked.
+/// Signals are declared inside `#[godot_api]` blocks, as functions without body annotated with `#[signal]`. They can accept parameters.
 ///
 /// ```no_run
 /// # use godot::prelude::*;
@@ -341,6 +432,36 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// For each class with signals, a type-safe signal collection is generated. It is accessible via `self.signals()` inside the class
+/// (requires a `Base<T>` field), or via `Gd::signals()` from the outside. Each signal provides `emit()` with the declared parameters,
+/// as well as `connect()` for Rust closures with matching parameter types (Godot 4.2+):
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node)]
+/// struct Monster {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Monster {
+///     #[signal]
+///     fn hit(damage: i64);
+///
+///     #[func]
+///     fn attack(&mut self) {
+///         self.signals().hit().emit(42);
+///     }
+/// }
+///
+/// fn observe(monster: &Gd<Monster>) {
+///     monster.signals().hit().connect(|damage: i64| {
+///         godot_print!("hit for {damage}");
+///     });
+/// }
+/// ```
+///
 /// # Further class customization
 ///
 /// ## Running code in the editor
@@ -439,7 +560,20 @@ use crate::util::ident;
 /// #     fn init(base: godot::obj::Base<Self::Base>) -> Self { todo!() }
 /// # }
 /// ```
-#[proc_macro_derive(GodotClass, attributes(class, base, hint, var, export, init, signal))]
+#[proc_macro_derive(
+    GodotClass,
+    attributes(
+        class,
+        base,
+        hint,
+        var,
+        export,
+        export_group,
+        export_subgroup,
+        init,
+        signal
+    )
+)]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
 }
@@ -625,6 +759,111 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///
 /// Make sure you understand the limitations in the [tutorial](https://godot-rust.github.io/book/register/virtual-functions.html).
 ///
+/// ## Default parameters
+///
+/// Trailing parameters can be made optional for callers from GDScript, by providing a default value with `#[opt(default = ...)]`.
+/// The defaults are registered with Godot, so they show up in the editor's documentation and are filled in for omitted arguments.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Spawner {}
+///
+/// #[godot_api]
+/// impl Spawner {
+///     #[func]
+///     fn spawn(&mut self, count: i32, #[opt(default = 1.0)] speed: f32) {
+///         // GDScript can call spawn(3) or spawn(3, 2.5).
+///     }
+/// }
+/// ```
+///
+/// Once a parameter has a default value, all parameters following it need one as well. Defaults are evaluated each time they are
+/// needed, and must be convertible to the parameter type. Rust code calling the method directly still has to pass all arguments.
+///
+/// ## Variadic functions
+///
+/// A function whose last parameter has type `&[&Variant]` is registered as variadic (`MethodFlags::VARARG`). Callers may pass any number of
+/// arguments after the regular parameters; these are collected into the slice without conversion.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Logger {}
+///
+/// #[godot_api]
+/// impl Logger {
+///     #[func]
+///     fn log(&self, level: i32, values: &[&Variant]) {
+///         // GDScript can call log(1), log(1, "a") or log(1, "a", 2, Vector2.ZERO).
+///     }
+/// }
+/// ```
+///
+/// Variadic functions cannot be virtual, have default parameters, or be used as `#[rpc]`.
+///
+/// ## Fallible functions
+///
+/// A function may return `Result<T, E>`, where `T: ToGodot` and `E: Display`. `Ok` values are returned to the caller as usual. An `Err`
+/// is reported as a script error pointing to the function's source location, and the caller receives `nil` instead -- or the value
+/// of `#[func(error_fallback = expr)]`, if specified. In both cases, Godot sees the return type as `Variant`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct SaveGame {}
+///
+/// #[godot_api]
+/// impl SaveGame {
+///     #[func]
+///     fn load_level(&self, path: GString) -> Result<i64, String> {
+///         Err(format!("level {path} not found"))
+///     }
+///
+///     #[func(error_fallback = -1)]
+///     fn parse_score(&self, text: GString) -> Result<i64, std::num::ParseIntError> {
+///         text.to_string().parse()
+///     }
+/// }
+/// ```
+///
+/// `Result` return types are not supported for `#[func(virtual)]`.
+///
+/// ## RPC attributes
+///
+/// Methods of classes inheriting `Node` can be made callable over the network by adding `#[rpc]` next to `#[func]`. The keys mirror
+/// GDScript's `@rpc` annotation; omitted ones use the same defaults (`authority`, `unreliable`, `call_remote`, `channel = 0`).
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[func]
+///     #[rpc(any_peer, reliable, call_local, channel = 2)]
+///     fn jump(&mut self, height: f32) {
+///         // ...
+///     }
+///
+///     fn on_input(&mut self) {
+///         // Generated helpers, calling Node::rpc() and Node::rpc_id() with type-checked arguments.
+///         self.rpc_jump(2.5);
+///         self.rpc_id_jump(1, 2.5);
+///     }
+/// }
+/// ```
+///
+/// The configuration is registered via `Node::rpc_config()` whenever an instance of the class is created, so no manual setup is needed.
+/// The helpers `rpc_{method}()` and `rpc_id_{method}()` require a `Base<T>` field.
+///
 /// # Constants and signals
 ///
 /// Please refer to [the book](https://godot-rust.github.io/book/register/constants.html).
@@ -633,14 +872,53 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
     translate(input, class::attribute_godot_api)
 }
 
-/// Derive macro for [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) on structs.
+/// Makes a Rust trait implementation of a user class available through [`DynGd`](../obj/struct.DynGd.html).
+///
+/// Apply this attribute to `impl Trait for MyClass` blocks, where `MyClass` is a class deriving `GodotClass`. The impl block itself
+/// is kept as-is. In addition, the class is registered as an implementor of `dyn Trait`, so that a `DynGd<Base, dyn Trait>` can be
+/// created from any object of that class, even if its concrete type is only known at runtime:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// trait Health {
+///     fn hit_points(&self) -> i32;
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Monster {
+///     hp: i32,
+/// }
+///
+/// #[godot_dyn]
+/// impl Health for Monster {
+///     fn hit_points(&self) -> i32 {
+///         self.hp
+///     }
+/// }
+///
+/// fn print_health(node: Gd<Node>) {
+///     match DynGd::<Node, dyn Health>::try_from_gd(node) {
+///         Ok(health) => godot_print!("HP: {}", health.dyn_bind().hit_points()),
+///         Err(err) => godot_print!("no health: {err}"),
+///     }
+/// }
+/// ```
+///
+/// A class can implement several traits this way. Generic traits are supported, as long as the impl block itself is not generic.
+#[proc_macro_attribute]
+pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
+    translate(input, class::attribute_godot_dyn)
+}
+
+/// Derive macro for [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) on structs and enums.
 ///
 /// This derive macro also derives [`ToGodot`](../builtin/meta/trait.ToGodot.html) and [`FromGodot`](../builtin/meta/trait.FromGodot.html).
 ///
 /// # Choosing a Via type
 ///
 /// To specify the `Via` type that your type should be converted to, you must use the `godot` attribute.
-/// There are currently two modes supported.
+/// The following modes are supported.
 ///
 /// ## `transparent`
 ///
@@ -759,12 +1037,107 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// assert_eq!(MyEnum::B.to_godot(), 10);
 /// assert_eq!(MyEnum::C.to_godot(), 11);
 /// ```
+///
+/// ## `dictionary`
+///
+/// If you specify `#[godot(dictionary)]` on a struct with named fields, it is converted to a [`Dictionary`](../builtin/struct.Dictionary.html)
+/// with one entry per field. Keys are the field names as strings, values are the fields converted via `ToGodot`. Each field type must
+/// implement `ToGodot` and `FromGodot`.
+///
+/// Conversion back from a dictionary fails if a field's key is missing or its value cannot be converted.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(dictionary)]
+/// struct Stats {
+///     hp: i32,
+///     speed: f32,
+/// }
+///
+/// let stats = Stats { hp: 100, speed: 2.5 };
+/// assert_eq!(stats.to_godot(), dict! { "hp": 100, "speed": 2.5 });
+/// ```
+///
+/// Combined with the [`Var`] and [`Export`] derives, such structs can be used as `#[var]` and `#[export]` fields without declaring a
+/// `Resource` class. The editor shows them as a nested dictionary, and they are serialized as dictionaries in scene files.
+///
+/// Fields cannot have their own export hints: Godot does not support hints for individual dictionary entries, so the editor shows plain
+/// values, e.g. no range slider for an `i32`. If you need per-field hints, declare a `Resource` class with `#[export]` fields instead.
+///
+/// `#[godot(dictionary)]` can also be used on enums whose variants carry data. Such an enum is converted to a dictionary with the
+/// variant name under the `"variant"` key, plus one entry per field of that variant. Fields of tuple variants are keyed by their index.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(dictionary)]
+/// enum Shape {
+///     Point,
+///     Circle { radius: f32 },
+///     Rect(f32, f32),
+/// }
+///
+/// assert_eq!(Shape::Circle { radius: 1.5 }.to_godot(), dict! { "variant": "Circle", "radius": 1.5 });
+/// assert_eq!(Shape::Rect(2.0, 3.0).to_godot(), dict! { "variant": "Rect", 0: 2.0, 1: 3.0 });
+/// ```
+///
+/// ## `array`
+///
+/// If you specify `#[godot(array)]` on a tuple struct, it is converted to a [`VariantArray`](../builtin/type.VariantArray.html) with
+/// one element per field, in declaration order.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(array)]
+/// struct Range(i32, i32);
+///
+/// assert_eq!(Range(1, 5).to_godot(), varray![1, 5]);
+/// ```
+///
+/// ## Field attributes
+///
+/// Fields of `dictionary` and `array` types support the following attributes:
+///
+/// - `#[godot(rename = name)]` or `#[godot(rename = "name")]` uses `"name"` as the dictionary key instead of the field name. The
+///   string form allows keys that are not valid identifiers. Also allowed on enum variants, to change the value of the `"variant"`
+///   tag. Fields of enum variants cannot use the key `"variant"`, as it is reserved for the tag.
+/// - `#[godot(default)]` or `#[godot(default = expr)]` uses `Default::default()` or `expr` if the field is absent during
+///   conversion from Godot, instead of failing. In arrays, only trailing elements may be absent.
+/// - `#[godot(skip)]` excludes the field from conversion in both directions. It is initialized with `Default::default()`, or the
+///   expression given to `#[godot(default = expr)]`.
+///
+/// If conversion from Godot fails for a nested value, [`ConvertError::path()`](../builtin/meta/struct.ConvertError.html#method.path)
+/// returns the location of the offending value, such as `stats.hp` or `points[2]`.
+///
+/// # Typed arrays
+///
+/// Types deriving `GodotConvert` can be stored in [`Array<T>`](../builtin/struct.Array.html). The array is represented in Godot by
+/// its `Via` type, e.g. `Array<MyEnum>` with `#[godot(via = i64)]` is an `Array[int]`. When such an array is converted from Godot,
+/// every element is checked, and the error's path contains the index of the first invalid element. If the type also derives
+/// `Export`, exported arrays carry the element's hint, such as the enum's variant names.
+///
+/// Since Godot has no nested typed arrays, this requires the `Via` type to be an array element itself. A newtype over a typed array
+/// cannot be stored in another array:
+/// ```compile_fail
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(transparent)]
+/// struct Scores(Array<i64>);
+///
+/// let nested: Array<Scores> = Array::new();
+/// ```
 #[proc_macro_derive(GodotConvert, attributes(godot))]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
 }
 
-/// Derive macro for [`Var`](../register/property/trait.Var.html) on enums.
+/// Derive macro for [`Var`](../register/property/trait.Var.html) on enums and `#[godot(dictionary)]`/`#[godot(array)]` types.
 ///
 /// This expects a derived [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) implementation, using a manual
 /// implementation of `GodotConvert` may lead to incorrect values being displayed in Godot.
@@ -773,7 +1146,7 @@ pub fn derive_var(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_var)
 }
 
-/// Derive macro for [`Export`](../register/property/trait.Export.html) on enums.
+/// Derive macro for [`Export`](../register/property/trait.Export.html) on enums and `#[godot(dictionary)]`/`#[godot(array)]` types.
 ///
 /// See also [`Var`].
 #[proc_macro_derive(Export, attributes(godot))]
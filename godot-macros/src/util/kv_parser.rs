@@ -109,6 +109,40 @@ impl KvParser {
         }
     }
 
+    /// Handles a key that can occur with an identifier or a string literal as the value, e.g. `rename = name` or
+    /// `rename = "name"`. Returns the key (with its span) and the value as a string.
+    pub fn handle_ident_or_string(&mut self, key: &str) -> ParseResult<Option<(Ident, String)>> {
+        let (key, value) = match self.map.remove_entry(&ident(key)) {
+            None => return Ok(None),
+            Some((key, None)) => {
+                return bail!(
+                    key,
+                    "expected `{key}` to be followed by `= identifier` or `= \"string\"`"
+                );
+            }
+            Some((key, Some(value))) => (key, value),
+        };
+
+        let string = match value.single()? {
+            TokenTree::Ident(ident) => ident.to_string(),
+            TokenTree::Literal(literal) => {
+                let repr = literal.to_string();
+                match repr.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+                    Some(content) if !content.contains('\\') => content.to_string(),
+                    _ => {
+                        return bail!(
+                            literal,
+                            "expected identifier or string literal without escape sequences"
+                        )
+                    }
+                }
+            }
+            other => return bail!(other, "expected identifier or string literal"),
+        };
+
+        Ok(Some((key, string)))
+    }
+
     /// Handles an array of the form `[elem1, elem2, ...]`.
     pub fn handle_array(&mut self, key: &str) -> ParseResult<Option<ListParser>> {
         ListParser::new_from_kv(self, key, Delimiter::Bracket)
@@ -119,6 +153,32 @@ impl KvParser {
         ListParser::new_from_kv(self, key, Delimiter::Parenthesis)
     }
 
+    /// Handles a nested list of keys and values of the form `(key1, key2 = value, ...)`, which is parsed like an attribute.
+    pub fn handle_nested(&mut self, key: &str) -> ParseResult<Option<KvParser>> {
+        let value = match self.map.remove_entry(&ident(key)) {
+            None => return Ok(None),
+            Some((key, None)) => {
+                return bail!(key, "expected `{key}` to be followed by `= (...)`");
+            }
+            Some((_, Some(value))) => value.single()?,
+        };
+
+        let group = match value {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => group,
+            other => return bail!(other, "expected `(...)`"),
+        };
+
+        let attr_value = venial::AttributeValue::Group(
+            venial::GroupSpan::new(&group),
+            group.stream().into_iter().collect(),
+        );
+
+        Ok(Some(Self {
+            span: group.span(),
+            map: ParserState::parse(key.to_string(), &attr_value)?,
+        }))
+    }
+
     /// Handles an optional key that can occur with arbitrary tokens as the value.
     pub fn handle_expr(&mut self, key: &str) -> ParseResult<Option<TokenStream>> {
         match self.map.remove_entry(&ident(key)) {
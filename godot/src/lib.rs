@@ -154,9 +154,9 @@
 //!
 //! * **`experimental-threads`**
 //!
-//!   Experimental threading support. This enables `Send`/`Sync` traits for `Gd<T>` and makes the guard types `Gd`/`GdMut` aware of
-//!   multi-threaded references. There safety aspects are not ironed out yet; there is a high risk of unsoundness at the moment.
-//!   As this evolves, it is very likely that the API becomes more strict.<br><br>
+//!   Experimental threading support. This makes the guard types `Gd`/`GdMut` aware of multi-threaded references, and provides
+//!   `GdHandle<T>` to pass objects between threads. There safety aspects are not ironed out yet; there is a high risk
+//!   of unsoundness at the moment. As this evolves, it is very likely that the API becomes more strict.<br><br>
 //!
 //! * **`experimental-godot-api`**
 //!
@@ -223,7 +223,7 @@ const _: () = _validate_features();
 // Modules
 
 #[doc(inline)]
-pub use godot_core::{builtin, engine, log, obj};
+pub use godot_core::{builtin, engine, log, obj, task};
 
 #[doc(hidden)]
 pub use godot_core::sys;
@@ -239,7 +239,8 @@ pub mod init {
 /// Register/export Rust symbols to Godot: classes, methods, enums...
 pub mod register {
     pub use godot_core::property;
-    pub use godot_macros::{godot_api, Export, GodotClass, GodotConvert, Var};
+    pub use godot_core::RpcConfig;
+    pub use godot_macros::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
 }
 
 /// Testing facilities (unstable).
@@ -8,7 +8,7 @@
 pub use super::register::property::{Export, TypeStringHint, Var};
 
 // Re-export macros.
-pub use super::register::{godot_api, Export, GodotClass, GodotConvert, Var};
+pub use super::register::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
 
 pub use super::builtin::__prelude_reexport::*;
 pub use super::builtin::math::FloatExt as _;
@@ -21,7 +21,7 @@ pub use super::engine::{
 };
 pub use super::init::{gdextension, ExtensionLibrary, InitLevel};
 pub use super::log::*;
-pub use super::obj::{Base, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, OnReady};
+pub use super::obj::{Base, DynGd, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, OnReady};
 
 // Make trait methods available.
 pub use super::obj::EngineBitfield as _;
@@ -29,3 +29,4 @@ pub use super::obj::EngineEnum as _;
 pub use super::obj::NewAlloc as _;
 pub use super::obj::NewGd as _;
 pub use super::obj::WithBaseField as _; // base(), base_mut(), to_gd()
+pub use super::obj::WithUserSignals as _; // signals()
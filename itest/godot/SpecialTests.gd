@@ -55,3 +55,22 @@ func test_collision_object_2d_input_event():
 
 	window.queue_free()
 
+
+# Test that closures queued with `godot::task::run_on_main()` run in the next frame, both when queued from the main thread and from
+# worker threads. This needs frames to pass during the test, which is not possible with the standard API.
+func test_run_on_main_flushed_next_frame():
+	# `godot::task` requires Godot 4.2.
+	if not ClassDB.class_exists("RunOnMainTest"):
+		return
+
+	var test = ClassDB.instantiate("RunOnMainTest")
+	test.queue_from_main()
+
+	var expected_count := 1
+	if test.queue_from_thread():
+		expected_count += 1
+
+	assert_eq(test.run_count(), 0, "Queued closures should not run immediately")
+
+	await Engine.get_main_loop().process_frame
+	assert_eq(test.run_count(), expected_count, "Queued closures should run in the next frame")
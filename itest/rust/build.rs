@@ -185,7 +185,7 @@ fn collect_inputs() -> Vec<Input> {
         [-7, "godot", false, Vector2i(-77, 88)],
         varray![-7, "godot", false, Vector2i::new(-77, 88)]);
 
-    pushs!(inputs; Dictionary, Dictionary,
+    pushs!(inputs; Dictionary, VariantDictionary,
         r#"{"key": 83, -3: Vector2(1, 2), 0.03: true}"#,
         dict! { "key": 83, (-3): Vector2::new(1.0, 2.0), 0.03: true },
         true, true, None
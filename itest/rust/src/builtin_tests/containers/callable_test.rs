@@ -111,6 +111,59 @@ fn callable_call_return() {
     assert_eq!(callable.callv(varray!["string"]), Variant::nil());
 }
 
+#[itest]
+fn callable_call_typed() {
+    let obj = CallableTestObj::new_gd();
+
+    let result: GString = obj.callable("bar").call((10,)).expect("call succeeds");
+    assert_eq!(result, GString::from("10"));
+
+    let () = obj.callable("foo").call((25,)).expect("call succeeds");
+    assert_eq!(obj.bind().value, 25);
+}
+
+#[itest]
+fn callable_call_typed_errors() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("bar");
+
+    // Argument cannot be converted to i32 parameter.
+    let err = callable
+        .call::<_, GString>(("string",))
+        .expect_err("argument conversion fails");
+    assert_eq!(err.method_name(), "call");
+    assert!(err.to_string().contains("parameter #0"), "{err}");
+
+    // Wrong number of arguments.
+    let err = callable
+        .call::<_, GString>((1, 2))
+        .expect_err("argument count mismatch");
+    assert!(err.to_string().contains("2 arguments"), "{err}");
+
+    // Return type mismatch.
+    let err = callable
+        .call::<_, i64>((1,))
+        .expect_err("return conversion fails");
+    assert!(err.to_string().contains("return value"), "{err}");
+
+    // Method does not exist.
+    let err = obj
+        .callable("doesnt_exist")
+        .call::<_, Variant>(())
+        .expect_err("method does not exist");
+    assert!(err.to_string().contains("method not found"), "{err}");
+}
+
+#[itest]
+fn callable_call_typed_dead_object() {
+    let obj = Object::new_alloc();
+    let callable = obj.callable("get_class");
+    obj.free();
+
+    let err = callable.call::<_, GString>(()).expect_err("object is dead");
+    assert!(err.to_string().contains("has been freed"), "{err}");
+}
+
 #[itest]
 fn callable_call_engine() {
     let obj = Node2D::new_alloc();
@@ -143,6 +196,45 @@ fn callable_bindv() {
     );
 }
 
+#[itest]
+fn callable_bind_typed() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("bar").bind((10,));
+
+    assert_eq!(callable.bound_args(), varray![10]);
+    assert_eq!(callable.bound_args_count(), 1);
+    #[cfg(since_api = "4.3")]
+    assert_eq!(callable.arg_count(), 0);
+
+    let result: GString = callable.call(()).expect("call succeeds");
+    assert_eq!(result, GString::from("10"));
+}
+
+#[itest]
+fn callable_unbind() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("bar").unbind(2);
+
+    assert_eq!(callable.bound_args_count(), -2);
+    #[cfg(since_api = "4.3")]
+    assert_eq!(callable.arg_count(), 3);
+
+    // The two trailing arguments are ignored.
+    let result: GString = callable.call((7, "ignored", 3.5)).expect("call succeeds");
+    assert_eq!(result, GString::from("7"));
+}
+
+#[itest]
+fn callable_debug() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("bar").bind((42,));
+
+    let debug = format!("{callable:?}");
+    assert!(debug.contains("bar"), "{debug}");
+    assert!(debug.contains("CallableTestObj"), "{debug}");
+    assert!(debug.contains("42"), "{debug}");
+}
+
 // Testing https://github.com/godot-rust/gdext/issues/410
 
 #[derive(GodotClass)]
@@ -162,7 +254,7 @@ impl CallableRefcountTest {
 mod custom_callable {
     use super::*;
     use crate::framework::assert_eq_self;
-    use godot::builtin::Dictionary;
+    use godot::builtin::VariantDictionary;
     use std::fmt;
     use std::hash::Hash;
     use std::sync::{Arc, Mutex};
@@ -274,7 +366,7 @@ mod custom_callable {
         let a = Callable::from_custom(Adder::new_tracked(3, at.clone()));
         let b = Callable::from_custom(Adder::new_tracked(3, bt.clone()));
 
-        let mut dict = Dictionary::new();
+        let mut dict = VariantDictionary::new();
 
         dict.set(a, "hello");
         assert_eq!(hash_count(&at), 1, "hash needed for a dict key");
@@ -8,30 +8,30 @@
 use std::collections::{HashMap, HashSet};
 
 use godot::builtin::meta::{FromGodot, ToGodot};
-use godot::builtin::{dict, varray, Dictionary, Variant};
+use godot::builtin::{dict, varray, Dictionary, GString, Variant, VariantDictionary};
 use godot::sys::GdextBuild;
 
 use crate::framework::{expect_panic, itest};
 
 #[itest]
 fn dictionary_default() {
-    assert_eq!(Dictionary::default().len(), 0);
+    assert_eq!(VariantDictionary::default().len(), 0);
 }
 
 #[itest]
 fn dictionary_new() {
-    assert_eq!(Dictionary::new().len(), 0);
+    assert_eq!(VariantDictionary::new().len(), 0);
 }
 
 #[itest]
 fn dictionary_from_iterator() {
-    let dictionary = Dictionary::from_iter([("foo", 1), ("bar", 2)]);
+    let dictionary = VariantDictionary::from_iter([("foo", 1), ("bar", 2)]);
 
     assert_eq!(dictionary.len(), 2);
     assert_eq!(dictionary.get("foo"), Some(1.to_variant()), "key = \"foo\"");
     assert_eq!(dictionary.get("bar"), Some(2.to_variant()), "key = \"bar\"");
 
-    let dictionary = Dictionary::from_iter([(1, "foo"), (2, "bar")]);
+    let dictionary = VariantDictionary::from_iter([(1, "foo"), (2, "bar")]);
 
     assert_eq!(dictionary.len(), 2);
     assert_eq!(dictionary.get(1), Some("foo".to_variant()), "key = 1");
@@ -40,13 +40,13 @@ fn dictionary_from_iterator() {
 
 #[itest]
 fn dictionary_from() {
-    let dictionary = Dictionary::from(&HashMap::from([("foo", 1), ("bar", 2)]));
+    let dictionary = VariantDictionary::from(&HashMap::from([("foo", 1), ("bar", 2)]));
 
     assert_eq!(dictionary.len(), 2);
     assert_eq!(dictionary.get("foo"), Some(1.to_variant()), "key = \"foo\"");
     assert_eq!(dictionary.get("bar"), Some(2.to_variant()), "key = \"bar\"");
 
-    let dictionary = Dictionary::from(&HashMap::from([(1, "foo"), (2, "bar")]));
+    let dictionary = VariantDictionary::from(&HashMap::from([(1, "foo"), (2, "bar")]));
 
     assert_eq!(dictionary.len(), 2);
     assert_eq!(dictionary.get(1), Some("foo".to_variant()), "key = \"foo\"");
@@ -101,7 +101,7 @@ fn dictionary_clone() {
 
     #[allow(clippy::redundant_clone)]
     let clone = dictionary.clone();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).insert("final", 4);
+    VariantDictionary::from_variant(&clone.get("bar").unwrap()).insert("final", 4);
     assert_eq!(subdictionary.get("final"), Some(4.to_variant()));
 }
 
@@ -147,7 +147,7 @@ fn dictionary_duplicate_deep() {
         "bar": subdictionary.clone()
     };
     let clone = dictionary.duplicate_deep();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
+    VariantDictionary::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
     assert_eq!(
         subdictionary.get("baz"),
         Some(true.to_variant()),
@@ -166,7 +166,7 @@ fn dictionary_duplicate_shallow() {
         "bar": subdictionary.clone()
     };
     let mut clone = dictionary.duplicate_shallow();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
+    VariantDictionary::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
     assert_eq!(
         subdictionary.get("baz"),
         Some(4.to_variant()),
@@ -386,7 +386,7 @@ fn dictionary_iter() {
 #[itest]
 fn dictionary_iter_size_hint() {
     // Test a completely empty dict.
-    let dictionary = Dictionary::new();
+    let dictionary = VariantDictionary::new();
     let iter = dictionary.iter_shared();
     assert_eq!(iter.size_hint(), (0, Some(0)));
 
@@ -424,11 +424,11 @@ fn dictionary_iter_size_hint() {
 
 #[itest]
 fn dictionary_iter_equals_big() {
-    let dictionary: Dictionary = (0..1000).zip(0..1000).collect();
+    let dictionary: VariantDictionary = (0..1000).zip(0..1000).collect();
     let map: HashMap<i64, i64> = (0..1000).zip(0..1000).collect();
     let collected_map: HashMap<i64, i64> = dictionary.iter_shared().typed::<i64, i64>().collect();
     assert_eq!(map, collected_map);
-    let collected_dictionary: Dictionary = collected_map.into_iter().collect();
+    let collected_dictionary: VariantDictionary = collected_map.into_iter().collect();
     assert_eq!(dictionary, collected_dictionary);
 }
 
@@ -479,7 +479,7 @@ fn dictionary_iter_insert_after_completion() {
 
 #[itest]
 fn dictionary_iter_big() {
-    let dictionary: Dictionary = (0..256).zip(0..256).collect();
+    let dictionary: VariantDictionary = (0..256).zip(0..256).collect();
     let mut dictionary2 = dictionary.clone();
     let mut iter = dictionary.iter_shared();
 
@@ -549,7 +549,7 @@ fn dictionary_iter_panics() {
     expect_panic(
         "Dictionary containing integer keys should not be convertible to a HashSet<String>",
         || {
-            let dictionary: Dictionary = (0..10).zip(0..).collect();
+            let dictionary: VariantDictionary = (0..10).zip(0..).collect();
             let _set: HashSet<String> = dictionary.keys_shared().typed::<String>().collect();
         },
     );
@@ -557,7 +557,7 @@ fn dictionary_iter_panics() {
     expect_panic(
         "Dictionary containing integer entries should not be convertible to a HashMap<String,String>",
         || {
-            let dictionary: Dictionary = (0..10).zip(0..).collect();
+            let dictionary: VariantDictionary = (0..10).zip(0..).collect();
             let _set: HashMap<String,String> = dictionary.iter_shared().typed::<String,String>().collect();
         },
     );
@@ -657,7 +657,7 @@ fn dictionary_iter_erase() {
 
 #[itest]
 fn dictionary_should_format_with_display() {
-    let d = Dictionary::new();
+    let d = VariantDictionary::new();
     assert_eq!(format!("{d}"), "{  }");
 
     let d = dict! {
@@ -667,3 +667,64 @@ fn dictionary_should_format_with_display() {
     };
     assert_eq!(format!("{d}"), "{ one: 1, two: true, three: <null> }")
 }
+
+#[itest]
+fn dictionary_typed() {
+    let mut dictionary = Dictionary::<GString, i64>::new();
+    dictionary.set("one", 1);
+    dictionary.set("two", 2);
+
+    assert_eq!(dictionary.get("one"), Some(1));
+    assert_eq!(dictionary.insert("two", 22), Some(2));
+    assert_eq!(dictionary.remove("one"), Some(1));
+    assert_eq!(dictionary.find_key_by_value(22), Some(GString::from("two")));
+
+    let entries: Vec<(GString, i64)> = dictionary.iter_shared().collect();
+    assert_eq!(entries, vec![("two".into(), 22)]);
+
+    let keys: Vec<GString> = dictionary.keys_shared().collect();
+    assert_eq!(keys, vec![GString::from("two")]);
+}
+
+#[itest]
+fn dictionary_typed_insert_panics() {
+    let mut dictionary = Dictionary::<GString, i64>::new();
+
+    expect_panic("insert key of wrong type", || {
+        dictionary.set(1, 1);
+    });
+    expect_panic("insert value of wrong type", || {
+        dictionary.set("one", "one");
+    });
+
+    assert!(dictionary.is_empty());
+}
+
+#[itest]
+fn dictionary_typed_convert() {
+    let dictionary: Dictionary<GString, i64> = [("a", 1), ("b", 2)].into_iter().collect();
+
+    let back = dictionary
+        .to_variant()
+        .try_to::<Dictionary<GString, i64>>()
+        .expect("typed dictionary should convert back");
+    assert_eq!(back, dictionary);
+
+    let mismatched = dict! { "a": 1, "b": "two" };
+    let err = mismatched.to_variant().try_to::<Dictionary<GString, i64>>();
+    assert!(err.is_err(), "untyped dictionary with mismatched values");
+}
+
+#[itest]
+#[cfg(since_api = "4.4")]
+fn dictionary_typed_property_hint() {
+    use godot::engine::global::PropertyHint;
+    use godot::register::property::Var;
+
+    let hint = <Dictionary<GString, i64> as Var>::property_hint();
+    assert_eq!(hint.hint, PropertyHint::DICTIONARY_TYPE);
+    assert_eq!(hint.hint_string, "String;int".into());
+
+    let untyped = <VariantDictionary as Var>::property_hint();
+    assert_eq!(untyped.hint, PropertyHint::NONE);
+}
@@ -11,8 +11,8 @@ use godot::builtin::meta::ToGodot;
 use godot::builtin::{Callable, GString, Signal, StringName, Variant};
 use godot::register::{godot_api, GodotClass};
 
-use godot::engine::{Object, RefCounted};
-use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
+use godot::engine::{Node, Object, RefCounted};
+use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField, WithUserSignals};
 use godot::sys;
 
 use crate::framework::itest;
@@ -93,6 +93,242 @@ fn signals() {
     emitter.free();
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type-safe signals
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct TypedEmitter {
+    last_damage: i64,
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl TypedEmitter {
+    #[signal]
+    fn damage_taken(amount: i64, source: GString);
+
+    #[signal]
+    fn destroyed();
+
+    #[func]
+    fn take_damage(&mut self, amount: i64) {
+        self.last_damage = amount;
+        self.signals()
+            .damage_taken()
+            .emit(amount, GString::from("trap"));
+    }
+}
+
+#[itest]
+fn signal_typed_untyped_conversion() {
+    let emitter = TypedEmitter::new_alloc();
+
+    let signal = emitter.signals().damage_taken().to_untyped();
+    assert_eq!(signal.name(), StringName::from("damage_taken"));
+    assert_eq!(signal.object_id(), Some(emitter.instance_id()));
+
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+mod typed_signal_connect {
+    use super::*;
+    use godot::obj::InstanceId;
+    use std::sync::{Arc, Mutex};
+
+    #[itest]
+    fn signal_typed_emit_connect() {
+        let mut emitter = TypedEmitter::new_alloc();
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let received_clone = received.clone();
+        emitter
+            .signals()
+            .damage_taken()
+            .connect(move |amount: i64, source: GString| {
+                received_clone
+                    .lock()
+                    .unwrap()
+                    .push((amount, source.to_string()));
+            });
+
+        emitter.bind_mut().take_damage(12);
+        emitter
+            .signals()
+            .damage_taken()
+            .emit(5, GString::from("fall"));
+
+        let received = received.lock().unwrap().clone();
+        assert_eq!(
+            received,
+            vec![(12, "trap".to_string()), (5, "fall".to_string())]
+        );
+
+        emitter.free();
+    }
+
+    #[itest]
+    fn signal_typed_untyped_emit() {
+        let mut emitter = TypedEmitter::new_alloc();
+        let count = Arc::new(Mutex::new(0));
+
+        let count_clone = count.clone();
+        emitter.signals().destroyed().connect(move || {
+            *count_clone.lock().unwrap() += 1;
+        });
+
+        emitter.emit_signal("destroyed".into(), &[]);
+        emitter.emit_signal("destroyed".into(), &[]);
+        assert_eq!(*count.lock().unwrap(), 2);
+
+        emitter.free();
+    }
+
+    #[itest]
+    fn signal_typed_reentrant_handler() {
+        let mut emitter = TypedEmitter::new_alloc();
+        let id: InstanceId = emitter.instance_id();
+        let observed = Arc::new(Mutex::new(0));
+
+        // Handler accesses the emitter while `take_damage()` still holds `&mut self`.
+        let observed_clone = observed.clone();
+        emitter
+            .signals()
+            .damage_taken()
+            .connect(move |_amount: i64, _source: GString| {
+                let emitter = Gd::<TypedEmitter>::from_instance_id(id);
+                *observed_clone.lock().unwrap() = emitter.bind().last_damage;
+            });
+
+        emitter.bind_mut().take_damage(77);
+        assert_eq!(*observed.lock().unwrap(), 77);
+
+        emitter.free();
+    }
+
+    #[itest]
+    fn signal_connect_fn_guard() {
+        let emitter = TypedEmitter::new_alloc();
+        let signal = Signal::from_object_signal(&emitter, "damage_taken");
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let received_clone = received.clone();
+        let guard = signal.connect_fn(move |amount: i64, source: GString| {
+            received_clone
+                .lock()
+                .unwrap()
+                .push((amount, source.to_string()));
+        });
+        assert!(guard.is_connected());
+
+        emitter
+            .signals()
+            .damage_taken()
+            .emit(1, GString::from("first"));
+        drop(guard);
+        emitter
+            .signals()
+            .damage_taken()
+            .emit(2, GString::from("second"));
+
+        let received = received.lock().unwrap().clone();
+        assert_eq!(received, vec![(1, "first".to_string())]);
+        assert!(signal.connections().is_empty());
+
+        emitter.free();
+    }
+
+    #[itest]
+    fn signal_typed_guard_forget() {
+        let emitter = TypedEmitter::new_alloc();
+        let count = Arc::new(Mutex::new(0));
+
+        let count_clone = count.clone();
+        let guard = emitter.signals().destroyed().connect_guarded(move || {
+            *count_clone.lock().unwrap() += 1;
+        });
+        guard.forget();
+
+        emitter.signals().destroyed().emit();
+        assert_eq!(*count.lock().unwrap(), 1);
+
+        emitter.free();
+    }
+
+    #[itest]
+    fn signal_typed_tracked_owner_freed() {
+        let emitter = TypedEmitter::new_alloc();
+        let owner = Object::new_alloc();
+        let count = Arc::new(Mutex::new(0));
+
+        let count_clone = count.clone();
+        let guard = emitter
+            .signals()
+            .destroyed()
+            .connect_tracked(owner.instance_id(), move || {
+                *count_clone.lock().unwrap() += 1;
+            });
+
+        emitter.signals().destroyed().emit();
+        owner.free();
+        emitter.signals().destroyed().emit();
+
+        assert_eq!(*count.lock().unwrap(), 1);
+        assert!(!guard.is_connected());
+
+        // Dropping after the owner is gone must not report errors.
+        drop(guard);
+        emitter.free();
+    }
+
+    #[itest]
+    fn signal_guard_outlives_emitter() {
+        let emitter = TypedEmitter::new_alloc();
+        let guard = emitter.signals().destroyed().connect_guarded(|| {});
+
+        emitter.free();
+        assert!(!guard.is_connected());
+    }
+
+    #[itest]
+    fn signal_typed_engine_inherited() {
+        let mut node = Node::new_alloc();
+        let count = Arc::new(Mutex::new(0));
+
+        // `property_list_changed` is declared by Object and reached through Node's signal collection.
+        let count_clone = count.clone();
+        node.signals().property_list_changed().connect(move || {
+            *count_clone.lock().unwrap() += 1;
+        });
+
+        node.notify_property_list_changed();
+        assert_eq!(*count.lock().unwrap(), 1);
+
+        node.free();
+    }
+
+    #[itest]
+    fn signal_typed_engine_object_param() {
+        let node = Node::new_alloc();
+        let child = Node::new_alloc();
+        let received = Arc::new(Mutex::new(None));
+
+        let received_clone = received.clone();
+        node.signals()
+            .child_entered_tree()
+            .connect(move |entered: Gd<Node>| {
+                *received_clone.lock().unwrap() = Some(entered.instance_id());
+            });
+
+        node.signals().child_entered_tree().emit(child.clone());
+        assert_eq!(*received.lock().unwrap(), Some(child.instance_id()));
+
+        child.free();
+        node.free();
+    }
+}
+
 #[itest]
 fn instantiate_signal() {
     let mut object = RefCounted::new_gd();
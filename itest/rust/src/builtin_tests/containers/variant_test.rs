@@ -12,7 +12,7 @@ use godot::builtin::meta::{FromGodot, ToGodot};
 use godot::builtin::{
     dict, varray, GString, NodePath, Signal, StringName, Variant, Vector2, Vector3,
 };
-use godot::builtin::{Basis, Dictionary, VariantArray, VariantOperator, VariantType};
+use godot::builtin::{Basis, VariantArray, VariantDictionary, VariantOperator, VariantType};
 use godot::engine::{Node, Node2D};
 use godot::obj::{Gd, InstanceId, NewAlloc};
 use godot::sys::GodotFfi;
@@ -126,7 +126,7 @@ fn variant_bad_conversions() {
     //);
     Variant::nil()
         .to_variant()
-        .try_to::<Dictionary>()
+        .try_to::<VariantDictionary>()
         .expect_err("`nil` should not convert to `Dictionary`");
 }
 
@@ -379,7 +379,7 @@ fn variant_type_correct() {
         VariantType::Array
     );
     assert_eq!(
-        Dictionary::default().to_variant().get_type(),
+        VariantDictionary::default().to_variant().get_type(),
         VariantType::Dictionary
     );
 }
@@ -409,7 +409,7 @@ fn variant_booleanize_correct() {
     assert!(varray![""].to_variant().booleanize());
     assert!(dict! { "Key": 50 }.to_variant().booleanize());
 
-    assert!(!Dictionary::new().to_variant().booleanize());
+    assert!(!VariantDictionary::new().to_variant().booleanize());
     assert!(!varray![].to_variant().booleanize());
     assert!(!0.to_variant().booleanize());
     assert!(!Variant::nil().booleanize());
@@ -7,7 +7,7 @@
 
 use godot::builtin::meta::{ConvertError, FromGodot, GodotConvert, ToGodot};
 use godot::builtin::{
-    dict, Array, Dictionary, GString, Variant, VariantArray, Vector2, Vector2Axis,
+    dict, Array, GString, Variant, VariantArray, VariantDictionary, Vector2, Vector2Axis,
 };
 use godot::engine::{Node, Resource};
 use godot::obj::{Gd, NewAlloc};
@@ -101,7 +101,7 @@ impl Foo {
 }
 
 impl GodotConvert for Foo {
-    type Via = Dictionary;
+    type Via = VariantDictionary;
 }
 
 impl ToGodot for Foo {
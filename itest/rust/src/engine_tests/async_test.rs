@@ -0,0 +1,229 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{GString, Signal};
+use godot::engine::Object;
+use godot::obj::{Gd, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+use godot::task;
+
+use crate::framework::itest;
+
+fn object_with_signal() -> (Gd<Object>, Signal) {
+    let mut object = Object::new_alloc();
+    object.add_user_signal("progress".into());
+
+    let signal = Signal::from_object_signal(&object, "progress");
+    (object, signal)
+}
+
+#[itest]
+fn async_signal_future_resolves() {
+    let (mut object, signal) = object_with_signal();
+    let received = Rc::new(RefCell::new(Vec::new()));
+
+    let received_clone = received.clone();
+    let handle = task::spawn(async move {
+        let (amount, label): (i64, GString) = signal.to_future().await;
+        received_clone
+            .borrow_mut()
+            .push((amount, label.to_string()));
+
+        let (amount, label): (i64, GString) = signal.to_future().await;
+        received_clone
+            .borrow_mut()
+            .push((amount, label.to_string()));
+    });
+    assert!(handle.is_pending());
+
+    object.emit_signal("progress".into(), &[1.to_variant(), "one".to_variant()]);
+    assert!(handle.is_pending());
+
+    object.emit_signal("progress".into(), &[2.to_variant(), "two".to_variant()]);
+    assert!(!handle.is_pending());
+
+    let received = received.borrow().clone();
+    assert_eq!(
+        received,
+        vec![(1, "one".to_string()), (2, "two".to_string())]
+    );
+
+    object.free();
+}
+
+#[itest]
+fn async_signal_future_cancelled_on_free() {
+    let (object, signal) = object_with_signal();
+    let resumed = Rc::new(Cell::new(false));
+
+    let resumed_clone = resumed.clone();
+    let handle = task::spawn(async move {
+        let _: (i64, GString) = signal.to_future().await;
+        resumed_clone.set(true);
+    });
+
+    object.free();
+
+    assert!(!handle.is_pending());
+    assert!(!resumed.get());
+}
+
+#[itest]
+fn async_signal_future_cancelled_on_invalid_args() {
+    let (mut object, signal) = object_with_signal();
+    let resumed = Rc::new(Cell::new(false));
+
+    let resumed_clone = resumed.clone();
+    let handle = task::spawn(async move {
+        let _: (i64, GString) = signal.to_future().await;
+        resumed_clone.set(true);
+    });
+
+    // Prints an error instead of panicking inside the executor.
+    object.emit_signal("progress".into(), &["one".to_variant(), 1.to_variant()]);
+
+    assert!(!handle.is_pending());
+    assert!(!resumed.get());
+
+    object.free();
+}
+
+#[itest]
+fn async_task_cancel() {
+    let (mut object, signal) = object_with_signal();
+    let resumed = Rc::new(Cell::new(false));
+
+    let resumed_clone = resumed.clone();
+    let handle = task::spawn(async move {
+        let _: (i64, GString) = signal.to_future().await;
+        resumed_clone.set(true);
+    });
+
+    handle.cancel();
+
+    // Future was dropped, so the one-shot connection is removed as well.
+    object.emit_signal("progress".into(), &[1.to_variant(), "one".to_variant()]);
+    assert!(!resumed.get());
+    assert!(Signal::from_object_signal(&object, "progress")
+        .connections()
+        .is_empty());
+
+    object.free();
+}
+
+#[itest]
+fn async_task_completes_immediately() {
+    let done = Rc::new(Cell::new(false));
+
+    let done_clone = done.clone();
+    let handle = task::spawn(async move {
+        done_clone.set(true);
+    });
+
+    assert!(done.get());
+    assert!(!handle.is_pending());
+}
+
+#[itest]
+fn run_on_main_is_deferred() {
+    let ran = Arc::new(AtomicBool::new(false));
+
+    let ran_clone = ran.clone();
+    task::run_on_main(move || ran_clone.store(true, Ordering::Release));
+
+    // Flushed in the next frame, even when queued from the main thread. That it runs eventually is checked by
+    // `test_run_on_main_flushed_next_frame` in `SpecialTests.gd`, as frames cannot pass during this test.
+    assert!(!ran.load(Ordering::Acquire));
+}
+
+#[itest]
+#[cfg(feature = "experimental-threads")]
+fn run_on_main_from_other_thread() {
+    let ran = Arc::new(AtomicBool::new(false));
+
+    let ran_clone = ran.clone();
+    std::thread::spawn(move || {
+        task::run_on_main(move || ran_clone.store(true, Ordering::Release));
+    })
+    .join()
+    .unwrap();
+
+    // Not run on the worker thread, but queued for the main thread.
+    assert!(!ran.load(Ordering::Acquire));
+
+    // Flushes the queue.
+    task::run_on_main_blocking(|| {});
+    assert!(ran.load(Ordering::Acquire));
+}
+
+#[itest]
+fn run_on_main_blocking_on_main_thread() {
+    // On the main thread, the closure runs immediately instead of dead-locking.
+    let result = task::run_on_main_blocking(|| 6 * 7);
+    assert_eq!(result, 42);
+}
+
+#[itest]
+fn run_on_main_blocking_runs_queued_first() {
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    let order_clone = order.clone();
+    task::run_on_main(move || order_clone.lock().unwrap().push("queued"));
+
+    let order_clone = order.clone();
+    task::run_on_main_blocking(move || order_clone.lock().unwrap().push("blocking"));
+
+    assert_eq!(*order.lock().unwrap(), vec!["queued", "blocking"]);
+}
+
+// Used in `test_run_on_main_flushed_next_frame` in `SpecialTests.gd`.
+#[derive(GodotClass)]
+#[class(init)]
+pub struct RunOnMainTest {
+    run_count: Arc<AtomicUsize>,
+}
+
+#[godot_api]
+impl RunOnMainTest {
+    #[func]
+    fn queue_from_main(&self) {
+        let run_count = self.run_count.clone();
+        task::run_on_main(move || {
+            run_count.fetch_add(1, Ordering::AcqRel);
+        });
+    }
+
+    /// Queues a closure from a worker thread. Returns `false` if this is not supported without `experimental-threads`.
+    #[func]
+    fn queue_from_thread(&self) -> bool {
+        if !cfg!(feature = "experimental-threads") {
+            return false;
+        }
+
+        let run_count = self.run_count.clone();
+        std::thread::spawn(move || {
+            task::run_on_main(move || {
+                run_count.fetch_add(1, Ordering::AcqRel);
+            });
+        })
+        .join()
+        .unwrap();
+
+        true
+    }
+
+    #[func]
+    fn run_count(&self) -> i64 {
+        self.run_count.load(Ordering::Acquire) as i64
+    }
+}
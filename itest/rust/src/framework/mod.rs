@@ -104,6 +104,11 @@ pub fn passes_filter(filters: &[String], test_name: &str) -> bool {
 }
 
 pub fn expect_panic(context: &str, code: impl FnOnce()) {
+    expect_panic_message(context, code);
+}
+
+/// Like [`expect_panic`], but returns the panic message, so tests can check its contents.
+pub fn expect_panic_message(context: &str, code: impl FnOnce()) -> String {
     use std::panic;
 
     // Exchange panic hook, to disable printing during expected panics. Also disable gdext's panic printing.
@@ -119,10 +124,10 @@ pub fn expect_panic(context: &str, code: impl FnOnce()) {
     panic::set_hook(prev_hook);
     godot::private::set_error_print_level(prev_print_level);
 
-    assert!(
-        panic.is_err(),
-        "code should have panicked but did not: {context}",
-    );
+    match panic {
+        Ok(()) => panic!("code should have panicked but did not: {context}"),
+        Err(err) => godot::private::extract_panic_message(err),
+    }
 }
 
 /// Disable printing errors from Godot. Ideally we should catch and handle errors, ensuring they happen when
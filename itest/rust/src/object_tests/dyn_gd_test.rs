@@ -0,0 +1,217 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{StringName, Variant};
+use godot::engine::global::PropertyHint;
+use godot::engine::{Node, RefCounted};
+use godot::obj::{Base, DynGd, Gd, NewAlloc, NewGd};
+use godot::register::property::Export;
+use godot::register::{godot_api, godot_dyn, GodotClass};
+
+use crate::framework::{expect_panic, itest};
+
+#[itest]
+fn dyn_gd_bind() {
+    let mut health = Gd::from_object(RefcHealth { hp: 100 }).into_dyn::<dyn Health>();
+    assert_eq!(health.dyn_bind().get_hitpoints(), 100);
+
+    health.dyn_bind_mut().deal_damage(20);
+    assert_eq!(health.dyn_bind().get_hitpoints(), 80);
+
+    // Binding goes to the same instance as Gd::bind().
+    let gd: Gd<RefcHealth> = health.into_gd();
+    assert_eq!(gd.bind().hp, 80);
+}
+
+#[itest]
+fn dyn_gd_bind_conflict() {
+    let mut health = Gd::from_object(RefcHealth { hp: 100 }).into_dyn::<dyn Health>();
+    let other = health.clone();
+
+    let guard = health.dyn_bind_mut();
+    expect_panic("dyn_bind() while dyn_bind_mut() is active", || {
+        let _ = other.dyn_bind();
+    });
+    drop(guard);
+}
+
+#[itest]
+fn dyn_gd_from_base() {
+    let object = Gd::from_object(RefcHealth { hp: 42 }).upcast::<RefCounted>();
+
+    let health = DynGd::<RefCounted, dyn Health>::try_from_gd(object.clone())
+        .expect("RefcHealth implements Health");
+    assert_eq!(health.dyn_bind().get_hitpoints(), 42);
+    assert_eq!(*health, object);
+
+    // Different class, same trait.
+    let node = NodeHealth::new_alloc().upcast::<Node>();
+    let mut health =
+        DynGd::<Node, dyn Health>::try_from_gd(node).expect("NodeHealth implements Health");
+
+    health.dyn_bind_mut().deal_damage(5);
+    assert_eq!(health.dyn_bind().get_hitpoints(), 95);
+    health.into_gd().free();
+}
+
+#[itest]
+fn dyn_gd_from_base_error() {
+    let object = RefCounted::new_gd();
+
+    let err = DynGd::<RefCounted, dyn Health>::try_from_gd(object)
+        .expect_err("RefCounted does not implement Health");
+    assert!(
+        err.to_string().contains("does not implement trait"),
+        "{err}"
+    );
+}
+
+#[itest]
+fn dyn_gd_upcast() {
+    let health = NodeHealth::new_alloc().into_dyn::<dyn Health>();
+    let health: DynGd<Node, dyn Health> = health.upcast();
+
+    assert_eq!(health.dyn_bind().get_hitpoints(), 100);
+    health.into_gd().free();
+}
+
+#[itest]
+fn dyn_gd_variant_conversion() {
+    let health = Gd::from_object(RefcHealth { hp: 7 }).into_dyn::<dyn Health>();
+    let variant = health.to_variant();
+
+    let back = variant
+        .try_to::<DynGd<RefCounted, dyn Health>>()
+        .expect("conversion from variant");
+    assert_eq!(back.dyn_bind().get_hitpoints(), 7);
+
+    let unrelated = RefCounted::new_gd().to_variant();
+    assert!(unrelated.try_to::<DynGd<RefCounted, dyn Health>>().is_err());
+}
+
+#[itest]
+fn dyn_gd_func_param() {
+    let mut caller = DynGdCaller::new_alloc();
+    let health = Gd::from_object(RefcHealth { hp: 30 });
+
+    let result = caller.call(
+        StringName::from("damage_and_get"),
+        &[health.to_variant(), 10.to_variant()],
+    );
+    assert_eq!(result, 20.to_variant());
+    assert_eq!(health.bind().hp, 20);
+
+    // Object not implementing the trait: conversion fails.
+    let result = caller.try_call(
+        StringName::from("damage_and_get"),
+        &[RefCounted::new_gd().to_variant(), 10.to_variant()],
+    );
+    assert!(result.is_err());
+
+    caller.free();
+}
+
+#[itest]
+fn dyn_gd_export() {
+    let info = <Option<DynGd<Node, dyn Health>> as Export>::default_export_info();
+    assert_eq!(info.hint, PropertyHint::NODE_TYPE);
+    assert_eq!(info.hint_string, "Node".into());
+
+    let mut caller = DynGdCaller::new_alloc();
+    let target = NodeHealth::new_alloc();
+    caller.set("target".into(), target.to_variant());
+
+    let target_hp = caller
+        .bind()
+        .target
+        .as_ref()
+        .map(|t| t.dyn_bind().get_hitpoints());
+    assert_eq!(target_hp, Some(100));
+
+    caller.set("target".into(), Variant::nil());
+    assert!(caller.bind().target.is_none());
+
+    // Node that does not implement the trait: an error is printed, and the field keeps its value.
+    let unrelated = Node::new_alloc();
+    caller.set("target".into(), unrelated.to_variant());
+    assert!(caller.bind().target.is_none());
+
+    caller.set("target".into(), target.to_variant());
+    caller.set("target".into(), unrelated.to_variant());
+    assert_eq!(
+        caller.bind().target.as_ref().map(|t| (**t).clone()),
+        Some(target.clone().upcast::<Node>())
+    );
+
+    unrelated.free();
+    target.free();
+    caller.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Test classes
+
+trait Health {
+    fn get_hitpoints(&self) -> u8;
+    fn deal_damage(&mut self, damage: u8);
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct RefcHealth {
+    hp: u8,
+}
+
+#[godot_dyn]
+impl Health for RefcHealth {
+    fn get_hitpoints(&self) -> u8 {
+        self.hp
+    }
+
+    fn deal_damage(&mut self, damage: u8) {
+        self.hp -= damage;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct NodeHealth {
+    #[init(default = 100)]
+    hp: u8,
+}
+
+#[godot_dyn]
+impl Health for NodeHealth {
+    fn get_hitpoints(&self) -> u8 {
+        self.hp
+    }
+
+    fn deal_damage(&mut self, damage: u8) {
+        self.hp -= damage;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DynGdCaller {
+    #[export]
+    target: Option<DynGd<Node, dyn Health>>,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl DynGdCaller {
+    #[func]
+    fn damage_and_get(&self, target: DynGd<RefCounted, dyn Health>, damage: u8) -> u8 {
+        let mut target = target;
+        let mut health = target.dyn_bind_mut();
+        health.deal_damage(damage);
+        health.get_hitpoints()
+    }
+}
@@ -0,0 +1,53 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::{expect_panic, itest};
+use godot::engine::{Node, Resource};
+use godot::obj::{Gd, GdHandle, NewAlloc, NewGd};
+
+#[itest]
+fn gd_handle_reacquire() {
+    let node = Node::new_alloc();
+    let handle = GdHandle::new(&node);
+
+    assert_eq!(handle.instance_id(), node.instance_id());
+    assert_eq!(handle.get(), node);
+
+    node.free();
+    assert!(handle.try_get().is_err());
+    expect_panic("re-acquire dead object", || {
+        handle.get();
+    });
+}
+
+#[itest]
+fn gd_handle_resource_on_other_thread() {
+    let mut resource = Resource::new_gd();
+    resource.set_name("shared".into());
+
+    let handle = GdHandle::new(&resource);
+    let name = std::thread::spawn(move || handle.get().get_name().to_string())
+        .join()
+        .unwrap();
+
+    assert_eq!(name, "shared");
+}
+
+#[itest]
+fn gd_handle_node_on_other_thread() {
+    let node = Node::new_alloc();
+    let handle = GdHandle::new(&node);
+
+    let result = std::thread::spawn(move || handle.try_get().map(|_: Gd<Node>| ()))
+        .join()
+        .unwrap();
+
+    let err = result.expect_err("nodes are not accessible off the main thread");
+    assert!(err.to_string().contains("main thread"), "{err}");
+
+    node.free();
+}
@@ -7,7 +7,10 @@
 
 mod base_test;
 mod class_rename_test;
+mod dyn_gd_test;
 mod dynamic_call_test;
+#[cfg(feature = "experimental-threads")]
+mod gd_handle_test;
 mod init_level_test;
 mod object_swap_test;
 mod object_test;
@@ -16,11 +16,11 @@ use godot::engine::{
     file_access, Area2D, Camera3D, Engine, FileAccess, IRefCounted, Node, Node3D, Object,
     RefCounted,
 };
-use godot::obj::{Base, Gd, Inherits, InstanceId, NewAlloc, NewGd, RawGd};
+use godot::obj::{Base, BindError, Gd, Inherits, InstanceId, NewAlloc, NewGd, RawGd};
 use godot::register::{godot_api, GodotClass};
 use godot::sys::{self, interface_fn, GodotFfi};
 
-use crate::framework::{expect_panic, itest, TestContext};
+use crate::framework::{expect_panic, expect_panic_message, itest, TestContext};
 
 // TODO:
 // * make sure that ptrcalls are used when possible (ie. when type info available; maybe GDScript integration test)
@@ -247,6 +247,62 @@ fn object_user_bind_after_free() {
     });
 }
 
+#[itest]
+fn object_user_try_bind() {
+    let mut obj = RefcPayload::new_gd();
+    let mut copy = obj.clone();
+
+    {
+        let _guard1 = obj.bind();
+        let _guard2 = obj.bind();
+        assert!(copy.try_bind().is_ok());
+
+        let err = copy.try_bind_mut().expect_err("shared borrows exist");
+        assert!(
+            matches!(err, BindError::SharedBound { count: 2, .. }),
+            "{err:?}"
+        );
+
+        // Borrow sites are only recorded in Debug mode.
+        let expected_sites = if cfg!(debug_assertions) { 2 } else { 0 };
+        assert_eq!(err.borrow_sites().len(), expected_sites);
+    }
+
+    {
+        let _guard = obj.bind_mut();
+        let err = copy.try_bind().expect_err("bound exclusively");
+        assert!(matches!(err, BindError::ExclusivelyBound { .. }), "{err:?}");
+
+        let err = copy.try_bind_mut().expect_err("bound exclusively");
+        assert!(matches!(err, BindError::ExclusivelyBound { .. }), "{err:?}");
+    }
+
+    copy.try_bind_mut().expect("all guards released").value = 7;
+    assert_eq!(obj.try_bind().unwrap().value, 7);
+}
+
+#[itest]
+fn object_user_bind_panic_lists_borrows() {
+    let mut obj = RefcPayload::new_gd();
+    let mut copy = obj.clone();
+
+    let bind_line = line!() + 1;
+    let guard = obj.bind();
+
+    let msg = expect_panic_message("bind_mut() while bound", || {
+        let _ = copy.bind_mut();
+    });
+    drop(guard);
+
+    assert!(msg.contains("already bound by 1 shared borrow(s)"), "{msg}");
+
+    // Borrow sites are only recorded in Debug mode.
+    if cfg!(debug_assertions) {
+        let site = format!("shared borrow at {}:{bind_line}:", file!());
+        assert!(msg.contains(&site), "{msg}");
+    }
+}
+
 #[itest]
 fn object_user_free_during_bind() {
     let obj = Gd::from_object(ObjPayload {});
@@ -6,11 +6,14 @@
  */
 
 use godot::builtin::meta::{GodotConvert, ToGodot};
-use godot::builtin::{dict, Color, Dictionary, GString, Variant, VariantType};
+use godot::builtin::{
+    dict, Array, Color, GString, NodePath, PackedInt32Array, PackedStringArray, StringName,
+    Variant, VariantArray, VariantDictionary, VariantType,
+};
 use godot::engine::global::{PropertyHint, PropertyUsageFlags};
 use godot::engine::{INode, IRefCounted, Node, Object, RefCounted, Resource, Texture};
 use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, NewAlloc, NewGd};
-use godot::register::property::{Export, PropertyHintInfo, Var};
+use godot::register::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use godot::register::{godot_api, Export, GodotClass, GodotConvert, Var};
 use godot::test::itest;
 
@@ -189,7 +192,7 @@ struct NotExportable {
 }
 
 impl GodotConvert for NotExportable {
-    type Via = Dictionary;
+    type Via = VariantDictionary;
 }
 
 impl Var for NotExportable {
@@ -444,6 +447,449 @@ fn override_export() {
     check_property(&property, "usage", PropertyUsageFlags::GROUP.ord());
 }
 
-fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
+#[derive(GodotClass)]
+#[class(init)]
+struct ExportGroups {
+    #[export]
+    ungrouped: i64,
+
+    #[export_group(name = "Movement", prefix = "movement_")]
+    #[export]
+    movement_speed: f32,
+
+    #[export_subgroup(name = "Jump")]
+    #[export]
+    movement_jump_height: f32,
+
+    #[export_group(name = "Stats")]
+    #[export_subgroup(name = "Health", prefix = "health_")]
+    #[var]
+    health_max: i64,
+}
+
+#[itest]
+fn export_groups() {
+    let class = ExportGroups::new_gd();
+
+    let properties: Vec<(GString, i64)> = class
+        .get_property_list()
+        .iter_shared()
+        .map(|property| {
+            (
+                property.get_or_nil("name").to::<GString>(),
+                property.get_or_nil("usage").to::<i64>(),
+            )
+        })
+        .skip_while(|(name, _)| name != &GString::from("ungrouped"))
+        .collect();
+
+    let group = PropertyUsageFlags::GROUP.ord() as i64;
+    let subgroup = PropertyUsageFlags::SUBGROUP.ord() as i64;
+    let default = PropertyUsageFlags::DEFAULT.ord() as i64;
+    let none = PropertyUsageFlags::NONE.ord() as i64;
+
+    let expected = [
+        ("ungrouped", default),
+        ("Movement", group),
+        ("movement_speed", default),
+        ("Jump", subgroup),
+        ("movement_jump_height", default),
+        ("Stats", group),
+        ("Health", subgroup),
+        ("health_max", none),
+    ]
+    .map(|(name, usage)| (GString::from(name), usage));
+
+    // Properties of base classes follow.
+    assert_eq!(properties[..expected.len()], expected);
+
+    let prefix = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "Movement".to_variant())
+        .unwrap();
+    check_property(&prefix, "hint_string", "movement_");
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct ExportHints {
+    #[export(node_path = (Node2D, Sprite2D))]
+    node_path: NodePath,
+
+    #[export(color_no_alpha)]
+    colors: Array<Color>,
+
+    #[export(multiline)]
+    texts: PackedStringArray,
+
+    // Multiline hint only applies to strings and arrays of strings; other types keep their default hint.
+    #[export(multiline)]
+    name: StringName,
+
+    #[export(multiline)]
+    ints: Array<i64>,
+
+    #[export(expression)]
+    expression: GString,
+
+    #[export(storage)]
+    storage: i64,
+
+    #[export(custom = (hint = ENUM, hint_string = "Low,High", usage_flags = [DEFAULT, READ_ONLY]))]
+    custom: i64,
+
+    #[export(element = (range = (0, 10)))]
+    levels: Array<i64>,
+
+    #[export(element = (enum = (Low, High)))]
+    modes: PackedInt32Array,
+
+    // Elements are untyped arrays themselves, each holding ints.
+    #[export(element = (custom = (hint = TYPE_STRING, hint_string = "2:int")))]
+    grid: Array<VariantArray>,
+}
+
+#[itest]
+fn export_hints() {
+    let class = ExportHints::new_gd();
+    let property = |name: &str| {
+        class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let node_path = property("node_path");
+    check_property(
+        &node_path,
+        "hint",
+        PropertyHint::NODE_PATH_VALID_TYPES.ord(),
+    );
+    check_property(&node_path, "hint_string", "Node2D,Sprite2D");
+
+    let colors = property("colors");
+    check_property(&colors, "hint", PropertyHint::TYPE_STRING.ord());
+    check_property(
+        &colors,
+        "hint_string",
+        format!(
+            "{}/{}:",
+            VariantType::Color as i32,
+            PropertyHint::COLOR_NO_ALPHA.ord()
+        ),
+    );
+
+    let texts = property("texts");
+    check_property(&texts, "hint", PropertyHint::TYPE_STRING.ord());
+    check_property(
+        &texts,
+        "hint_string",
+        format!(
+            "{}/{}:",
+            VariantType::String as i32,
+            PropertyHint::MULTILINE_TEXT.ord()
+        ),
+    );
+
+    let name = property("name");
+    check_property(&name, "hint", PropertyHint::NONE.ord());
+
+    let ints = property("ints");
+    check_property(&ints, "hint", PropertyHint::TYPE_STRING.ord());
+    check_property(&ints, "hint_string", i64::type_string());
+
+    let expression = property("expression");
+    check_property(&expression, "hint", PropertyHint::EXPRESSION.ord());
+
+    let storage = property("storage");
+    check_property(&storage, "usage", PropertyUsageFlags::STORAGE.ord());
+
+    let custom = property("custom");
+    check_property(&custom, "hint", PropertyHint::ENUM.ord());
+    check_property(&custom, "hint_string", "Low,High");
+    check_property(
+        &custom,
+        "usage",
+        PropertyUsageFlags::DEFAULT.ord() | PropertyUsageFlags::READ_ONLY.ord(),
+    );
+
+    let levels = property("levels");
+    check_property(&levels, "hint", PropertyHint::TYPE_STRING.ord());
+    check_property(
+        &levels,
+        "hint_string",
+        format!(
+            "{}/{}:0,10",
+            VariantType::Int as i32,
+            PropertyHint::RANGE.ord()
+        ),
+    );
+
+    let modes = property("modes");
+    check_property(&modes, "hint", PropertyHint::TYPE_STRING.ord());
+    check_property(
+        &modes,
+        "hint_string",
+        format!(
+            "{}/{}:Low,High",
+            VariantType::Int as i32,
+            PropertyHint::ENUM.ord()
+        ),
+    );
+
+    let grid = property("grid");
+    check_property(&grid, "hint", PropertyHint::TYPE_STRING.ord());
+    check_property(
+        &grid,
+        "hint_string",
+        format!(
+            "{}/{}:2:int",
+            VariantType::Array as i32,
+            PropertyHint::TYPE_STRING.ord()
+        ),
+    );
+}
+
+#[itest]
+fn export_array_element_hints() {
+    assert_eq!(
+        TestEnum::type_string(),
+        format!(
+            "{}/{}:A:0,B:1,C:2",
+            VariantType::Int as i32,
+            PropertyHint::ENUM.ord()
+        )
+    );
+
+    let resource_element = format!(
+        "{}/{}:CustomResource",
+        VariantType::Object as i32,
+        PropertyHint::RESOURCE_TYPE.ord()
+    );
+
+    assert_eq!(
+        Array::<Gd<CustomResource>>::default_export_info(),
+        PropertyHintInfo {
+            hint: PropertyHint::TYPE_STRING,
+            hint_string: resource_element.clone().into(),
+        }
+    );
+
+    assert_eq!(
+        Array::<Option<Gd<CustomResource>>>::type_string(),
+        format!("{}:{resource_element}", VariantType::Array as i32)
+    );
+
+    assert_eq!(
+        Array::<TestEnum>::default_export_info(),
+        PropertyHintInfo {
+            hint: PropertyHint::TYPE_STRING,
+            hint_string: TestEnum::type_string().into(),
+        }
+    );
+}
+
+#[itest]
+fn export_nested_array_hints() {
+    // Godot has no nested typed arrays; inner arrays are untyped.
+    assert_eq!(
+        Array::<VariantArray>::type_string(),
+        format!(
+            "{}:{}:Array",
+            VariantType::Array as i32,
+            VariantType::Array as i32
+        )
+    );
+
+    assert_eq!(
+        Array::<VariantArray>::default_export_info(),
+        PropertyHintInfo {
+            hint: PropertyHint::TYPE_STRING,
+            hint_string: format!("{}:Array", VariantType::Array as i32).into(),
+        }
+    );
+}
+
+#[itest]
+#[cfg(since_api = "4.2")]
+fn var_array_of_enum_hint() {
+    // Registered as `Array[int]`, since the enum is represented as `i64`.
+    assert_eq!(
+        Array::<TestEnum>::property_hint(),
+        PropertyHintInfo {
+            hint: PropertyHint::ARRAY_TYPE,
+            hint_string: "int".into(),
+        }
+    );
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct VarHooks {
+    #[var(validate = clamp_health, on_change = health_changed)]
+    health: i64,
+
+    #[var(notify_property_list_changed)]
+    mode: i64,
+
+    health_changes: u32,
+    base: Base<RefCounted>,
+}
+
+impl VarHooks {
+    fn clamp_health(&self, value: i64) -> i64 {
+        value.clamp(0, 100)
+    }
+
+    fn health_changed(&mut self) {
+        self.health_changes += 1;
+    }
+}
+
+#[itest]
+fn var_setter_hooks() {
+    let mut obj = VarHooks::new_gd();
+
+    obj.bind_mut().set_health(150);
+    assert_eq!(obj.bind().health, 100);
+    assert_eq!(obj.bind().health_changes, 1);
+
+    obj.set("health".into(), (-5).to_variant());
+    assert_eq!(obj.bind().health, 0);
+    assert_eq!(obj.bind().health_changes, 2);
+
+    obj.set("mode".into(), 2.to_variant());
+    assert_eq!(obj.bind().mode, 2);
+}
+
+#[itest]
+#[cfg(since_api = "4.2")]
+fn var_setter_notify_property_list_changed() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use godot::builtin::Callable;
+
+    let mut obj = VarHooks::new_gd();
+
+    let notified = Arc::new(AtomicBool::new(false));
+    let notified_in = notified.clone();
+    obj.connect(
+        "property_list_changed".into(),
+        Callable::from_fn("notified", move |_args| {
+            notified_in.store(true, Ordering::Relaxed);
+            Ok(Variant::nil())
+        }),
+    );
+
+    obj.set("mode".into(), 2.to_variant());
+    assert_eq!(obj.bind().mode, 2);
+    assert!(notified.load(Ordering::Relaxed));
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct PropertyRevert {
+    #[export]
+    #[init(default = 10)]
+    speed: i64,
+
+    #[var]
+    #[init(default = GString::from("unnamed"))]
+    name: GString,
+
+    #[export]
+    no_default: i64,
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct PropertyRevertOverride {
+    #[export]
+    #[init(default = 10)]
+    speed: i64,
+
+    #[export]
+    #[init(default = 20)]
+    height: i64,
+}
+
+#[godot_api]
+impl IRefCounted for PropertyRevertOverride {
+    fn property_get_revert(&self, property: StringName) -> Option<Variant> {
+        (property == StringName::from("speed")).then(|| 99.to_variant())
+    }
+}
+
+#[itest]
+fn property_revert_to_init_default() {
+    let obj = PropertyRevert::new_gd();
+
+    assert!(obj.property_can_revert("speed".into()));
+    assert_eq!(obj.property_get_revert("speed".into()), 10.to_variant());
+
+    assert!(obj.property_can_revert("name".into()));
+    assert_eq!(
+        obj.property_get_revert("name".into()),
+        "unnamed".to_variant()
+    );
+
+    assert!(!obj.property_can_revert("no_default".into()));
+}
+
+#[itest]
+fn property_revert_override() {
+    let obj = PropertyRevertOverride::new_gd();
+
+    assert!(obj.property_can_revert("speed".into()));
+    assert_eq!(obj.property_get_revert("speed".into()), 99.to_variant());
+
+    // Falls back to #[init(default)].
+    assert!(obj.property_can_revert("height".into()));
+    assert_eq!(obj.property_get_revert("height".into()), 20.to_variant());
+}
+
+#[derive(GodotConvert, Var, Export, Clone, PartialEq, Debug)]
+#[godot(dictionary)]
+struct Stats {
+    hp: i32,
+    speed: f32,
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct ExportDictionaryStruct {
+    #[export]
+    #[init(default = Stats { hp: 10, speed: 1.5 })]
+    stats: Stats,
+}
+
+#[itest]
+fn export_dictionary_struct() {
+    let mut obj = ExportDictionaryStruct::new_gd();
+
+    let property = obj
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "stats".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::Dictionary as i32);
+    check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+
+    assert_eq!(
+        obj.get("stats".into()),
+        dict! { "hp": 10, "speed": 1.5f32 }.to_variant()
+    );
+
+    obj.set(
+        "stats".into(),
+        dict! { "hp": 20, "speed": 3.0 }.to_variant(),
+    );
+    assert_eq!(obj.bind().stats, Stats { hp: 20, speed: 3.0 });
+}
+
+fn check_property(property: &VariantDictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
@@ -9,12 +9,13 @@
 
 use crate::framework::{itest, TestContext};
 
-use godot::builtin::meta::ToGodot;
+use godot::builtin::meta::{ClassName, PropertyInfo, ToGodot};
 use godot::builtin::{
     real, varray, Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array,
     PackedInt32Array, PackedStringArray, PackedVector2Array, PackedVector3Array, RealConv,
-    StringName, Variant, VariantArray, Vector2, Vector3,
+    StringName, Variant, VariantArray, VariantType, Vector2, Vector3,
 };
+use godot::engine::global::{PropertyHint, PropertyUsageFlags};
 use godot::engine::notify::NodeNotification;
 use godot::engine::resource_loader::CacheMode;
 use godot::engine::{
@@ -282,6 +283,57 @@ impl IRefCounted for SetTest {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[derive(GodotClass)]
+#[class(init)]
+struct PropertyListTest {
+    #[var]
+    is_circle: bool,
+    #[var]
+    radius: f64,
+    #[var]
+    extents: f64,
+
+    dynamic: i64,
+}
+
+#[godot_api]
+impl IRefCounted for PropertyListTest {
+    fn get_property(&self, property: StringName) -> Option<Variant> {
+        (property == StringName::from("dynamic")).then(|| self.dynamic.to_variant())
+    }
+
+    fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+        if property != StringName::from("dynamic") {
+            return false;
+        }
+
+        self.dynamic = value.to();
+        true
+    }
+
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        vec![PropertyInfo {
+            variant_type: VariantType::Int,
+            class_name: ClassName::none(),
+            property_name: "dynamic".into(),
+            hint: PropertyHint::NONE,
+            hint_string: GString::new(),
+            usage: PropertyUsageFlags::DEFAULT,
+        }]
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn validate_property(&self, property: &mut PropertyInfo) {
+        let hidden = if self.is_circle { "extents" } else { "radius" };
+
+        if property.property_name == StringName::from(hidden) {
+            property.usage = PropertyUsageFlags::NONE;
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[itest]
 fn test_to_string() {
     let _obj = VirtualMethodTest::new_gd();
@@ -563,6 +615,49 @@ fn test_set_sets_correct() {
     assert_eq!(obj.bind().settable, 500);
 }
 
+#[itest]
+fn test_get_property_list() {
+    let mut obj = PropertyListTest::new_gd();
+
+    let property = obj
+        .get_property_list()
+        .iter_shared()
+        .find(|property| property.get_or_nil("name") == "dynamic".to_variant())
+        .expect("dynamic property is listed");
+    assert_eq!(
+        property.get_or_nil("type"),
+        (VariantType::Int as i32).to_variant()
+    );
+
+    obj.set("dynamic".into(), 42.to_variant());
+    assert_eq!(obj.bind().dynamic, 42);
+    assert_eq!(obj.get("dynamic".into()), 42.to_variant());
+}
+
+#[itest]
+#[cfg(since_api = "4.2")]
+fn test_validate_property() {
+    use godot::obj::EngineBitfield;
+
+    let mut obj = PropertyListTest::new_gd();
+
+    let usage_of = |obj: &Gd<PropertyListTest>, name: &str| {
+        obj.get_property_list()
+            .iter_shared()
+            .find(|property| property.get_or_nil("name") == name.to_variant())
+            .map(|property| property.get_or_nil("usage").to::<u64>())
+            .expect("property is listed")
+    };
+
+    let none = PropertyUsageFlags::NONE.ord();
+    assert_eq!(usage_of(&obj, "radius"), none);
+    assert_ne!(usage_of(&obj, "extents"), none);
+
+    obj.bind_mut().is_circle = true;
+    assert_ne!(usage_of(&obj, "radius"), none);
+    assert_eq!(usage_of(&obj, "extents"), none);
+}
+
 // Used in `test_collision_object_2d_input_event` in `SpecialTests.gd`.
 #[derive(GodotClass)]
 #[class(init, base = RigidBody2D)]
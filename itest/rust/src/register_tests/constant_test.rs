@@ -174,6 +174,7 @@ godot::sys::plugin_add!(
             register_methods_constants_fn: ::godot::private::ErasedRegisterFn {
                 raw: ::godot::private::callbacks::register_user_methods_constants::<HasOtherConstants>,
             },
+            register_rpcs_fn: None,
         },
         init_level: HasOtherConstants::INIT_LEVEL,
     }
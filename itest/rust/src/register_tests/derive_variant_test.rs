@@ -7,8 +7,8 @@
 
 use std::fmt::Debug;
 
-use godot::builtin::meta::ToGodot;
-use godot::builtin::{GString, Vector2};
+use godot::builtin::meta::{FromGodot, ToGodot};
+use godot::builtin::{array, dict, varray, Array, GString, Vector2};
 use godot::register::GodotConvert;
 
 use crate::common::roundtrip;
@@ -46,6 +46,52 @@ enum EnumInty {
     E,
 }
 
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(dictionary)]
+struct DictStruct {
+    hp: i32,
+    speed: f32,
+    name: GString,
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(dictionary)]
+struct DictStructAttributes {
+    #[godot(rename = health)]
+    hp: i32,
+    #[godot(default = 1.0)]
+    speed: f32,
+    #[godot(skip)]
+    cache: Option<i32>,
+    nested: DictStruct,
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(array)]
+struct ArrayStruct(i32, GString, #[godot(default)] bool);
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(dictionary)]
+enum DataEnum {
+    Unit,
+    Tuple(i32, GString),
+    Named {
+        x: f32,
+        y: f32,
+    },
+    #[godot(rename = renamed)]
+    Other {
+        #[godot(rename = val)]
+        value: i64,
+    },
+    #[godot(rename = "quoted name")]
+    Quoted {
+        // Would collide with the tag, so must be renamed.
+        #[godot(rename = "variant-kind")]
+        variant: i64,
+    },
+}
+
 #[itest]
 fn newtype_tuple_struct() {
     roundtrip(TupleNewtype("hello!".into()));
@@ -86,6 +132,181 @@ fn enum_inty() {
     assert_eq!(EnumInty::E.to_godot(), 2);
 }
 
+#[itest]
+fn dictionary_struct() {
+    let value = DictStruct {
+        hp: 100,
+        speed: 2.5,
+        name: "Knight".into(),
+    };
+    roundtrip(value.clone());
+
+    let dict = value.to_godot();
+    assert_eq!(dict.len(), 3);
+    assert_eq!(dict.get_or_nil("hp"), 100.to_variant());
+    assert_eq!(dict.get_or_nil("speed"), 2.5f32.to_variant());
+    assert_eq!(dict.get_or_nil("name"), "Knight".to_variant());
+}
+
+#[itest]
+fn dictionary_struct_errors() {
+    let missing = dict! { "hp": 100, "speed": 2.5 };
+    assert!(DictStruct::try_from_godot(missing).is_err());
+
+    let wrong_type = dict! { "hp": "full", "speed": 2.5, "name": "Knight" };
+    assert!(DictStruct::try_from_godot(wrong_type).is_err());
+}
+
+#[itest]
+fn dictionary_struct_attributes() {
+    let nested = DictStruct {
+        hp: 5,
+        speed: 0.5,
+        name: "Squire".into(),
+    };
+    let value = DictStructAttributes {
+        hp: 100,
+        speed: 2.5,
+        cache: None,
+        nested: nested.clone(),
+    };
+    roundtrip(value.clone());
+
+    let dict = value.to_godot();
+    assert_eq!(dict.len(), 3);
+    assert_eq!(dict.get_or_nil("health"), 100.to_variant());
+    assert!(!dict.contains_key("hp"));
+    assert!(!dict.contains_key("cache"));
+
+    let without_speed = dict! { "health": 100, "nested": nested.to_variant() };
+    let back = DictStructAttributes::try_from_godot(without_speed).unwrap();
+    assert_eq!(back.speed, 1.0);
+    assert_eq!(back.cache, None);
+}
+
+#[itest]
+fn dictionary_struct_error_path() {
+    let bad_nested = dict! {
+        "health": 100,
+        "nested": dict! { "hp": "full", "speed": 2.5, "name": "Squire" },
+    };
+    let err = DictStructAttributes::try_from_godot(bad_nested).unwrap_err();
+    assert_eq!(err.path(), "nested.hp");
+
+    let missing = dict! { "nested": dict! { "hp": 1, "speed": 2.5, "name": "Squire" } };
+    let err = DictStructAttributes::try_from_godot(missing).unwrap_err();
+    assert_eq!(err.path(), "health");
+}
+
+#[itest]
+fn array_struct() {
+    let value = ArrayStruct(7, "seven".into(), true);
+    roundtrip(value.clone());
+
+    assert_eq!(value.to_godot(), varray![7, "seven", true]);
+
+    let defaulted = ArrayStruct::try_from_godot(varray![7, "seven"]).unwrap();
+    assert_eq!(defaulted, ArrayStruct(7, "seven".into(), false));
+
+    let err = ArrayStruct::try_from_godot(varray![7]).unwrap_err();
+    assert_eq!(err.path(), "[1]");
+
+    let err = ArrayStruct::try_from_godot(varray![7, 8, true]).unwrap_err();
+    assert_eq!(err.path(), "[1]");
+
+    assert!(ArrayStruct::try_from_godot(varray![7, "seven", true, 4]).is_err());
+}
+
+#[itest]
+fn data_enum() {
+    roundtrip(DataEnum::Unit);
+    roundtrip(DataEnum::Tuple(3, "three".into()));
+    roundtrip(DataEnum::Named { x: 1.0, y: 2.0 });
+    roundtrip(DataEnum::Other { value: -4 });
+
+    assert_eq!(DataEnum::Unit.to_godot(), dict! { "variant": "Unit" });
+    assert_eq!(
+        DataEnum::Tuple(3, "three".into()).to_godot(),
+        dict! { "variant": "Tuple", 0: 3, 1: "three" }
+    );
+    assert_eq!(
+        DataEnum::Named { x: 1.0, y: 2.0 }.to_godot(),
+        dict! { "variant": "Named", "x": 1.0f32, "y": 2.0f32 }
+    );
+    assert_eq!(
+        DataEnum::Other { value: -4 }.to_godot(),
+        dict! { "variant": "renamed", "val": -4 }
+    );
+
+    roundtrip(DataEnum::Quoted { variant: 2 });
+    assert_eq!(
+        DataEnum::Quoted { variant: 2 }.to_godot(),
+        dict! { "variant": "quoted name", "variant-kind": 2 }
+    );
+}
+
+#[itest]
+fn data_enum_errors() {
+    let err = DataEnum::try_from_godot(dict! { "x": 1.0 }).unwrap_err();
+    assert_eq!(err.path(), "variant");
+
+    let err = DataEnum::try_from_godot(dict! { "variant": "Circle" }).unwrap_err();
+    assert_eq!(err.path(), "variant");
+
+    let err = DataEnum::try_from_godot(dict! { "variant": "Named", "x": 1.0 }).unwrap_err();
+    assert_eq!(err.path(), "Named.y");
+
+    let err =
+        DataEnum::try_from_godot(dict! { "variant": "Tuple", 0: "three", 1: "three" }).unwrap_err();
+    assert_eq!(err.path(), "Tuple[0]");
+}
+
+#[itest]
+fn array_of_derived_enum() {
+    let array: Array<EnumInty> = array![EnumInty::A, EnumInty::C, EnumInty::D];
+    roundtrip(array.clone());
+
+    // Stored as `Array[int]` in Godot.
+    let ints = array.to_variant().try_to::<Array<i64>>().unwrap();
+    assert_eq!(ints, array![10, 12, 1]);
+
+    let back = ints.to_variant().try_to::<Array<EnumInty>>().unwrap();
+    assert_eq!(back.get(1), EnumInty::C);
+}
+
+#[itest]
+fn array_of_derived_enum_errors() {
+    let ints: Array<i64> = array![10, 11, 99];
+    let err = ints.to_variant().try_to::<Array<EnumInty>>().unwrap_err();
+    assert_eq!(err.path(), "[2]");
+
+    let strings: Array<GString> = array!["A".into(), "X".into()];
+    let err = strings
+        .to_variant()
+        .try_to::<Array<EnumStringy>>()
+        .unwrap_err();
+    assert_eq!(err.path(), "[1]");
+}
+
+#[itest]
+fn array_of_engine_enum() {
+    use godot::engine::global::Side;
+    use godot::obj::EngineEnum;
+
+    let array: Array<Side> = array![Side::LEFT, Side::BOTTOM];
+    roundtrip(array.clone());
+
+    let ints = array.to_variant().try_to::<Array<i64>>().unwrap();
+    assert_eq!(
+        ints,
+        array![Side::LEFT.ord() as i64, Side::BOTTOM.ord() as i64]
+    );
+
+    let invalid: Array<i64> = array![0, 1234];
+    let err = invalid.to_variant().try_to::<Array<Side>>().unwrap_err();
+    assert_eq!(err.path(), "[1]");
+}
+
 macro_rules! test_inty {
     ($T:ident, $test_name:ident, $class_name:ident) => {
         #[derive(GodotConvert, Clone, PartialEq, Debug)]
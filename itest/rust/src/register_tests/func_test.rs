@@ -8,8 +8,10 @@
 // Needed for Clippy to accept #[cfg(all())]
 #![allow(clippy::non_minimal_cfg)]
 
-use crate::framework::itest;
+use crate::framework::{itest, suppress_godot_print};
+use godot::engine::global::MethodFlags;
 use godot::engine::ClassDb;
+use godot::obj::EngineBitfield;
 use godot::prelude::*;
 
 #[derive(GodotClass)]
@@ -33,6 +35,38 @@ impl FuncObj {
         GString::from("static")
     }
 
+    #[func]
+    fn concat_with_defaults(
+        &self,
+        a: i32,
+        #[opt(default = 10)] b: i32,
+        #[opt(default = GString::from("!"))] suffix: GString,
+    ) -> GString {
+        format!("{}{suffix}", a + b).into()
+    }
+
+    #[func]
+    fn sum_varargs(&self, base: i64, rest: &[&Variant]) -> i64 {
+        base + rest.iter().map(|arg| arg.to::<i64>()).sum::<i64>()
+    }
+
+    #[func]
+    fn count_varargs(rest: &[&Variant]) -> i64 {
+        rest.len() as i64
+    }
+
+    #[func]
+    fn parse_int(&self, text: GString) -> Result<i64, std::num::ParseIntError> {
+        text.to_string().parse()
+    }
+
+    #[func(error_fallback = -1)]
+    fn parse_int_or_negative(text: GString) -> Result<i64, String> {
+        text.to_string()
+            .parse()
+            .map_err(|_| format!("invalid int: {text}"))
+    }
+
     #[cfg(all())]
     fn returns_hello_world(&self) -> GString {
         GString::from("Hello world!")
@@ -296,6 +330,100 @@ fn cfg_removes_or_keeps_signals() {
     assert!(!class_has_signal::<GdSelfObj>("cfg_removes_signal"));
 }
 
+#[itest]
+fn func_default_parameters() {
+    let mut object = Gd::from_object(FuncObj);
+    let method = StringName::from("concat_with_defaults");
+
+    let result = object.call(method.clone(), &[1.to_variant()]);
+    assert_eq!(result, "11!".to_variant());
+
+    let result = object.call(method.clone(), &[1.to_variant(), 2.to_variant()]);
+    assert_eq!(result, "3!".to_variant());
+
+    let result = object.call(method, &[1.to_variant(), 2.to_variant(), "?".to_variant()]);
+    assert_eq!(result, "3?".to_variant());
+}
+
+#[itest]
+fn func_default_parameters_registered() {
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(FuncObj::class_name().to_string_name())
+        .no_inheritance(true)
+        .done();
+
+    let method = methods
+        .iter_shared()
+        .find(|method| method.get("name") == Some("concat_with_defaults".to_variant()))
+        .expect("method is registered");
+
+    let default_args = method.get("default_args").expect("default_args key");
+    assert_eq!(default_args, varray![10, "!"].to_variant());
+}
+
+#[itest]
+fn func_varargs() {
+    let mut object = Gd::from_object(FuncObj);
+    let method = StringName::from("sum_varargs");
+
+    let result = object.call(method.clone(), &[1.to_variant()]);
+    assert_eq!(result, 1.to_variant());
+
+    let result = object.call(
+        method,
+        &[
+            1.to_variant(),
+            2.to_variant(),
+            3.to_variant(),
+            4.to_variant(),
+        ],
+    );
+    assert_eq!(result, 10.to_variant());
+
+    let result = object.call("count_varargs".into(), &["a".to_variant(), 2.to_variant()]);
+    assert_eq!(result, 2.to_variant());
+}
+
+#[itest]
+fn func_varargs_registered() {
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(FuncObj::class_name().to_string_name())
+        .no_inheritance(true)
+        .done();
+
+    let method = methods
+        .iter_shared()
+        .find(|method| method.get("name") == Some("sum_varargs".to_variant()))
+        .expect("method is registered");
+
+    let flags = method.get("flags").expect("flags key").to::<u64>();
+    assert_ne!(flags & MethodFlags::VARARG.ord(), 0);
+}
+
+#[itest]
+fn func_result_ok() {
+    let mut object = Gd::from_object(FuncObj);
+
+    let result = object.call("parse_int".into(), &["42".to_variant()]);
+    assert_eq!(result, 42.to_variant());
+
+    let result = object.call("parse_int_or_negative".into(), &["7".to_variant()]);
+    assert_eq!(result, 7.to_variant());
+}
+
+#[itest]
+fn func_result_err() {
+    let mut object = Gd::from_object(FuncObj);
+
+    suppress_godot_print(|| {
+        let result = object.call("parse_int".into(), &["forty-two".to_variant()]);
+        assert_eq!(result, Variant::nil());
+
+        let result = object.call("parse_int_or_negative".into(), &["seven".to_variant()]);
+        assert_eq!(result, (-1).to_variant());
+    });
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers
 
@@ -11,6 +11,7 @@ mod derive_variant_test;
 mod func_test;
 mod gdscript_ffi_test;
 mod option_ffi_test;
+mod rpc_test;
 mod var_test;
 
 #[cfg(since_api = "4.3")]
@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::global::Error;
+use godot::engine::multiplayer_api::RpcMode;
+use godot::engine::multiplayer_peer::TransferMode;
+use godot::prelude::*;
+use godot::register::RpcConfig;
+
+use crate::framework::{itest, TestContext};
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+pub struct RpcTest {
+    last_value: i64,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl RpcTest {
+    #[func]
+    #[rpc(any_peer, reliable, call_local, channel = 2)]
+    fn set_value(&mut self, value: i64) {
+        self.last_value = value;
+    }
+
+    #[func(rename = renamed_rpc)]
+    #[rpc(call_local, unreliable_ordered)]
+    fn set_double(&mut self, value: i64) {
+        self.last_value = value * 2;
+    }
+
+    #[func]
+    #[rpc(authority, call_remote)]
+    fn set_remote(&mut self, value: i64) {
+        self.last_value = value;
+    }
+}
+
+#[itest]
+fn rpc_call_local(ctx: &TestContext) {
+    let node = RpcTest::new_alloc();
+    ctx.scene_tree.clone().add_child(node.clone().upcast());
+
+    // Fails with "unknown RPC" if configuration has not been registered upon instantiation.
+    let err = node.clone().bind_mut().rpc_set_value(42);
+    assert_eq!(err, Error::OK);
+    assert_eq!(node.bind().last_value, 42);
+
+    // Godot name is used for renamed functions; the helper keeps the Rust name.
+    let err = node.clone().bind_mut().rpc_set_double(5);
+    assert_eq!(err, Error::OK);
+    assert_eq!(node.bind().last_value, 10);
+
+    node.free();
+}
+
+#[itest]
+fn rpc_id_call_local(ctx: &TestContext) {
+    let node = RpcTest::new_alloc();
+    ctx.scene_tree.clone().add_child(node.clone().upcast());
+
+    // Without a multiplayer peer, the local peer has ID 1.
+    let err = node.clone().bind_mut().rpc_id_set_value(1, 17);
+    assert_eq!(err, Error::OK);
+    assert_eq!(node.bind().last_value, 17);
+
+    node.free();
+}
+
+#[itest]
+fn rpc_call_remote_not_executed_locally(ctx: &TestContext) {
+    let node = RpcTest::new_alloc();
+    ctx.scene_tree.clone().add_child(node.clone().upcast());
+
+    let err = node.clone().bind_mut().rpc_set_remote(99);
+    assert_eq!(err, Error::OK);
+    assert_eq!(node.bind().last_value, 0);
+
+    node.free();
+}
+
+#[itest]
+fn rpc_config_to_dictionary() {
+    let config = RpcConfig {
+        rpc_mode: RpcMode::ANY_PEER,
+        transfer_mode: TransferMode::RELIABLE,
+        call_local: true,
+        channel: 2,
+    };
+
+    let expected = dict! {
+        "rpc_mode": RpcMode::ANY_PEER.ord(),
+        "transfer_mode": TransferMode::RELIABLE.ord(),
+        "call_local": true,
+        "channel": 2,
+    };
+    assert_eq!(config.to_dictionary(), expected);
+}